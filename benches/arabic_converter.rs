@@ -0,0 +1,105 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use italian_numbers::{arabic_converter, roman_converter};
+
+/// The previous implementation of `arabic_converter`, kept as a baseline: it looks up the next character
+/// with `chars().nth`, allocates a `String` per character and validates by converting the result back.
+fn previous_arabic_converter(roman_number: String) -> Result<u16, &'static str> {
+    const ROMAN_LETTERS: [&str; 7] = ["I", "V", "X", "L", "C", "D", "M"];
+
+    fn get_arabic_number(letter: char) -> u16 {
+        match letter {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            'C' => 100,
+            'D' => 500,
+            'M' => 1000,
+            _ => 0,
+        }
+    }
+
+    let mut number: i16 = 0;
+    for (i, letter) in roman_number.chars().enumerate() {
+        if !ROMAN_LETTERS.iter().any(|e| *e == letter.to_string()) {
+            return Err("invalid roman number");
+        }
+
+        let mut arabic_number = get_arabic_number(letter) as i16;
+        if i + 1 < roman_number.len() {
+            let next_arabic_number = get_arabic_number(roman_number.chars().nth(i + 1).unwrap());
+            if (arabic_number as u16) < next_arabic_number {
+                arabic_number = -arabic_number;
+            }
+        }
+
+        number += arabic_number
+    }
+
+    if roman_converter(number as u16)? != roman_number {
+        return Err("invalid roman number");
+    }
+
+    Ok(number as u16)
+}
+
+fn roman_numbers() -> Vec<String> {
+    (1..=3_999)
+        .map(|number| roman_converter(number).unwrap())
+        .collect()
+}
+
+fn canonical(c: &mut Criterion) {
+    let roman_numbers = roman_numbers();
+    let mut group = c.benchmark_group("canonical 1..=3999");
+
+    group.bench_function("arabic_converter", |b| {
+        b.iter(|| {
+            for roman_number in &roman_numbers {
+                black_box(arabic_converter(black_box(roman_number.as_str())).unwrap());
+            }
+        })
+    });
+    group.bench_function("previous", |b| {
+        b.iter(|| {
+            for roman_number in &roman_numbers {
+                black_box(previous_arabic_converter(black_box(roman_number.clone())).unwrap());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn invalid(c: &mut Criterion) {
+    let roman_numbers = ["XIXIX", "MMMCMXCIIII", "catalogo", "IC", "MDCCCCX"];
+    let mut group = c.benchmark_group("invalid");
+
+    group.bench_function("arabic_converter", |b| {
+        b.iter(|| {
+            for roman_number in roman_numbers {
+                black_box(arabic_converter(black_box(roman_number)).unwrap_err());
+            }
+        })
+    });
+    group.bench_function("previous", |b| {
+        b.iter(|| {
+            for roman_number in roman_numbers {
+                black_box(
+                    previous_arabic_converter(black_box(roman_number.to_string())).unwrap_err(),
+                );
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn unicode(c: &mut Criterion) {
+    c.bench_function("unicode ⅯⅭⅯⅩⅭⅨ", |b| {
+        b.iter(|| black_box(arabic_converter(black_box("ⅯⅭⅯⅩⅭⅨ")).unwrap()))
+    });
+}
+
+criterion_group!(benches, canonical, invalid, unicode);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use italian_numbers::{
+    cardinal_batch_converter, cardinal_converter, italian_batch_converter, ordinal_batch_converter,
+    ordinal_converter,
+};
+
+const NUMBERS: u64 = 10_000;
+
+/// Numbers spread over the whole range, so that every multiplier is used.
+fn numbers() -> Vec<u64> {
+    (0..NUMBERS)
+        .map(|number| number * 99_999_991 % 1_000_000_000_000)
+        .collect()
+}
+
+fn cardinal(c: &mut Criterion) {
+    let numbers = numbers()
+        .into_iter()
+        .map(|number| number as f64)
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("cardinal");
+    group.throughput(Throughput::Elements(NUMBERS));
+
+    group.bench_function("cardinal_batch_converter", |b| {
+        b.iter(|| black_box(cardinal_batch_converter(black_box(&numbers), false)))
+    });
+
+    group.finish();
+}
+
+fn ordinal(c: &mut Criterion) {
+    let numbers = numbers();
+    let mut group = c.benchmark_group("ordinal");
+    group.throughput(Throughput::Elements(NUMBERS));
+
+    group.bench_function("ordinal_batch_converter", |b| {
+        b.iter(|| black_box(ordinal_batch_converter(black_box(&numbers), None)))
+    });
+
+    group.finish();
+}
+
+fn parser(c: &mut Criterion) {
+    let numbers = numbers();
+    let cardinals = numbers
+        .iter()
+        .map(|number| cardinal_converter(*number as f64, false).unwrap())
+        .collect::<Vec<_>>();
+    let ordinals = numbers
+        .iter()
+        .map(|number| ordinal_converter(*number, None).unwrap())
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Elements(NUMBERS));
+
+    group.bench_function("italian_batch_converter cardinals", |b| {
+        b.iter(|| black_box(italian_batch_converter(black_box(&cardinals))))
+    });
+    group.bench_function("italian_batch_converter ordinals", |b| {
+        b.iter(|| black_box(italian_batch_converter(black_box(&ordinals))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, cardinal, ordinal, parser);
+criterion_main!(benches);
@@ -1,16 +1,100 @@
-use crate::{roman_converter, ROMAN_LETTERS};
+use std::iter::Peekable;
+use std::str::Chars;
 
-fn get_arabic_number(letter: char) -> u16 {
+use crate::{roman_converter, ROMAN_UNICODE_NUMERALS};
+
+/// The one, five and ten symbols of the hundreds, tens and units.
+const PLACES: [(u16, char, char, char); 3] = [
+    (100, 'C', 'D', 'M'),
+    (10, 'X', 'L', 'C'),
+    (1, 'I', 'V', 'X'),
+];
+
+fn get_letters(letter: char) -> Option<&'static str> {
     match letter {
-        'I' => 1,
-        'V' => 5,
-        'X' => 10,
-        'L' => 50,
-        'C' => 100,
-        'D' => 500,
-        'M' => 1000,
-        _ => 0,
+        'I' | 'Ⅰ' => Some("I"),
+        'Ⅱ' => Some("II"),
+        'Ⅲ' => Some("III"),
+        'Ⅳ' => Some("IV"),
+        'V' | 'Ⅴ' => Some("V"),
+        'Ⅵ' => Some("VI"),
+        'Ⅶ' => Some("VII"),
+        'Ⅷ' => Some("VIII"),
+        'Ⅸ' => Some("IX"),
+        'X' | 'Ⅹ' => Some("X"),
+        'Ⅺ' => Some("XI"),
+        'Ⅻ' => Some("XII"),
+        'L' | 'Ⅼ' => Some("L"),
+        'C' | 'Ⅽ' => Some("C"),
+        'D' | 'Ⅾ' => Some("D"),
+        'M' | 'Ⅿ' => Some("M"),
+        _ => None,
+    }
+}
+
+/// The ASCII letters of a Roman number, with the Unicode Roman numeral characters expanded.
+/// A character that is not a Roman numeral is returned as `None`.
+struct Letters<'a> {
+    chars: Chars<'a>,
+    expansion: Chars<'static>,
+}
+
+impl Iterator for Letters<'_> {
+    type Item = Option<char>;
+
+    fn next(&mut self) -> Option<Option<char>> {
+        if let Some(letter) = self.expansion.next() {
+            return Some(Some(letter));
+        }
+
+        match get_letters(self.chars.next()?) {
+            Some(letters) => {
+                self.expansion = letters.chars();
+                self.expansion.next().map(Some)
+            }
+            None => Some(None),
+        }
+    }
+}
+
+fn repetitions_counter(letters: &mut Peekable<Letters>, letter: char, max: u16) -> u16 {
+    let mut count = 0;
+    while count < max && letters.next_if_eq(&Some(letter)).is_some() {
+        count += 1;
+    }
+
+    count
+}
+
+fn digit_parser(letters: &mut Peekable<Letters>, one: char, five: char, ten: char) -> u16 {
+    if letters.next_if_eq(&Some(five)).is_some() {
+        return 5 + repetitions_counter(letters, one, 3);
     }
+    if letters.next_if_eq(&Some(one)).is_none() {
+        return 0;
+    }
+    if letters.next_if_eq(&Some(five)).is_some() {
+        return 4;
+    }
+    if letters.next_if_eq(&Some(ten)).is_some() {
+        return 9;
+    }
+
+    1 + repetitions_counter(letters, one, 2)
+}
+
+/// Replaces the Unicode Roman numeral characters (U+2160 to U+216F) with the equivalent ASCII letters.
+pub(crate) fn unicode_normalizer(roman_number: &str) -> String {
+    roman_number
+        .chars()
+        .map(|letter| {
+            ROMAN_UNICODE_NUMERALS
+                .iter()
+                .find(|(numeral, _)| *numeral == letter)
+                .and_then(|(_, value)| roman_converter(*value).ok())
+                .unwrap_or_else(|| letter.to_string())
+        })
+        .collect()
 }
 
 /// Converts a Roman number to an Arabic number
@@ -19,6 +103,10 @@ fn get_arabic_number(letter: char) -> u16 {
 /// 
 /// * `roman_number` - The Roman number to convert.
 ///
+/// The Unicode Roman numeral characters (Ⅰ Ⅱ … Ⅻ, Ⅼ Ⅽ Ⅾ Ⅿ) are accepted and read as their ASCII equivalents.
+/// The number is read in a single pass, without allocating, and any UTF-8 input is rejected with
+/// "invalid roman number" unless it is a Roman number in the standard form.
+///
 /// # Examples
 ///
 /// ```
@@ -32,37 +120,47 @@ fn get_arabic_number(letter: char) -> u16 {
 ///
 /// let result = arabic_converter(String::from("MCMXC"));
 /// assert_eq!(Ok(1990), result);
+///
+/// let result = arabic_converter(String::from("ⅯⅭⅯⅩⅭ"));
+/// assert_eq!(Ok(1990), result);
+///
+/// let result = arabic_converter(String::from("Ⅻ"));
+/// assert_eq!(Ok(12), result);
+///
+/// let result = arabic_converter("XIXIX");
+/// assert_eq!(Err("invalid roman number"), result);
 /// ```
-pub fn arabic_converter(roman_number: String) -> Result<u16, &'static str> {
-    let mut number: i16 = 0;
-
-    for (i, letter) in roman_number.chars().enumerate() {
-        if !ROMAN_LETTERS
-            .iter()
-            .any(|e| e.to_string() == letter.to_string())
-        {
-            return Err("invalid roman number");
-        }
-
-        let mut arabic_number = get_arabic_number(letter) as i16;
+pub fn arabic_converter(roman_number: impl AsRef<str>) -> Result<u16, &'static str> {
+    let mut letters = Letters {
+        chars: roman_number.as_ref().chars(),
+        expansion: "".chars(),
+    }
+    .peekable();
 
-        if i + 1 < roman_number.len() {
-            let next_arabic_number = get_arabic_number(roman_number.chars().nth(i + 1).unwrap());
-            if (arabic_number as u16) < next_arabic_number {
-                arabic_number = -arabic_number;
-            }
+    let mut thousands: usize = 0;
+    while letters.next_if_eq(&Some('M')).is_some() {
+        thousands += 1;
+    }
+    if thousands > 3 {
+        if letters.any(|letter| letter.is_none()) {
+            return Err("invalid roman number");
         }
-
-        number += arabic_number
+        return Err("greater than 3999");
     }
 
-    let real_roman_number = roman_converter(number as u16)?;
+    let mut number = thousands as u16 * 1_000;
+    for (place, one, five, ten) in PLACES {
+        number += digit_parser(&mut letters, one, five, ten) * place;
+    }
 
-    if real_roman_number != roman_number {
+    if letters.next().is_some() {
         return Err("invalid roman number");
     }
+    if number < 1 {
+        return Err("lower than 1");
+    }
 
-    Ok(number as u16)
+    Ok(number)
 }
 
 /* TESTS */
@@ -209,6 +307,60 @@ mod tests {
         }
     }
 
+    mod round_trip {
+        use super::*;
+        use crate::roman_converter;
+
+        #[test]
+        fn test_all_numbers() {
+            for number in 1..=3_999 {
+                assert_eq!(
+                    arabic_converter(roman_converter(number).unwrap()),
+                    Ok(number)
+                );
+            }
+        }
+
+        #[test]
+        fn test_non_canonical() {
+            for roman_number in [
+                "IIII", "VV", "IL", "IC", "XM", "IXI", "XCX", "CMD", "IIV", "VX", "DCD",
+            ] {
+                assert_eq!(arabic_converter(roman_number), Err("invalid roman number"));
+            }
+        }
+    }
+
+    mod unicode {
+        use super::*;
+
+        #[test]
+        fn test_clock_face() {
+            assert_eq!(arabic_converter(String::from("Ⅳ")), Ok(4));
+            assert_eq!(arabic_converter(String::from("Ⅸ")), Ok(9));
+            assert_eq!(arabic_converter(String::from("Ⅻ")), Ok(12));
+        }
+
+        #[test]
+        fn test_letters() {
+            assert_eq!(arabic_converter(String::from("ⅯⅮⅭⅬⅩⅤⅠ")), Ok(1666));
+        }
+
+        #[test]
+        fn test_mixed() {
+            assert_eq!(arabic_converter(String::from("ⅩⅡ")), Ok(12));
+            assert_eq!(arabic_converter(String::from("MⅯⅫ")), Ok(2012));
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(
+                arabic_converter(String::from("ⅫⅫ")),
+                Err("invalid roman number")
+            );
+        }
+    }
+
     mod exceptions {
         use super::*;
 
@@ -221,5 +373,27 @@ mod tests {
         fn test_invalid_roman_number_2() {
             assert_eq!(arabic_converter(String::from("XIXIX")), Err("invalid roman number"));
         }
+
+        #[test]
+        fn test_multi_byte_characters() {
+            assert_eq!(arabic_converter("XÍV"), Err("invalid roman number"));
+            assert_eq!(arabic_converter("IVé"), Err("invalid roman number"));
+            assert_eq!(arabic_converter("€"), Err("invalid roman number"));
+        }
+
+        #[test]
+        fn test_greater_than_3999() {
+            assert_eq!(arabic_converter("MMMM"), Err("greater than 3999"));
+            assert_eq!(
+                arabic_converter("M".repeat(40_000)),
+                Err("greater than 3999")
+            );
+            assert_eq!(arabic_converter("MMMMZ"), Err("invalid roman number"));
+        }
+
+        #[test]
+        fn test_lower_than_1() {
+            assert_eq!(arabic_converter(""), Err("lower than 1"));
+        }
     }
 }
@@ -0,0 +1,186 @@
+use crate::italian_converter::word_parser;
+use crate::{cardinal_converter, ordinal_converter, Options};
+
+/// Converts a slice of numbers to their Italian cardinal representations
+///
+/// # Arguments
+///
+/// * `numbers` - The numbers to convert.
+/// * `include_decimals` - If true, the cents of every number are written after a slash, as `cardinal_converter` does.
+///
+/// Each result is the one of `cardinal_converter` for the number at the same position, so an invalid number
+/// does not stop the conversion of the others.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::cardinal_batch_converter;
+///
+/// let result = cardinal_batch_converter(&[1.0, 21.5, 1e12], true);
+/// assert_eq!(
+///     vec![
+///         Ok(String::from("uno/00")),
+///         Ok(String::from("ventuno/50")),
+///         Err("greater than 999.999.999.999"),
+///     ],
+///     result
+/// );
+/// ```
+pub fn cardinal_batch_converter(
+    numbers: &[f64],
+    include_decimals: bool,
+) -> Vec<Result<String, &'static str>> {
+    numbers
+        .iter()
+        .map(|number| cardinal_converter(*number, include_decimals))
+        .collect()
+}
+
+/// Converts a slice of numbers to their Italian ordinal representations
+///
+/// # Arguments
+///
+/// * `numbers` - The numbers to convert.
+/// * `options` - Optional settings for gender and plurality, the same for every number.
+///
+/// Each result is the one of `ordinal_converter` for the number at the same position.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{ordinal_batch_converter, Options};
+///
+/// let result = ordinal_batch_converter(&[1, 23], Some(Options::new(true, false)));
+/// assert_eq!(
+///     vec![Ok(String::from("prima")), Ok(String::from("ventitreesima"))],
+///     result
+/// );
+/// ```
+pub fn ordinal_batch_converter(
+    numbers: &[u64],
+    options: Option<Options>,
+) -> Vec<Result<String, &'static str>> {
+    numbers
+        .iter()
+        .map(|number| ordinal_converter(*number, options))
+        .collect()
+}
+
+/// Converts a slice of Italian word representations to numbers
+///
+/// # Arguments
+///
+/// * `words` - The Italian words to convert, as read by `italian_converter`.
+///
+/// Each result is the one of `italian_converter` for the word at the same position. The words are borrowed, so
+/// no `String` is allocated for them.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::italian_batch_converter;
+///
+/// let result = italian_batch_converter(&["ventuno", "2ª", "bla"]);
+/// assert_eq!(vec![Ok(21), Ok(2), Err("invalid word number")], result);
+/// ```
+pub fn italian_batch_converter<S: AsRef<str>>(words: &[S]) -> Vec<Result<i64, &'static str>> {
+    words
+        .iter()
+        .map(|word| word_parser(word.as_ref()))
+        .collect()
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{cardinal_batch_converter, italian_batch_converter, ordinal_batch_converter};
+    use crate::{cardinal_converter, italian_converter, ordinal_converter, Options};
+
+    mod cardinal {
+        use super::*;
+
+        #[test]
+        fn test_same_as_cardinal_converter() {
+            let numbers = (0..2_000)
+                .map(|number| number as f64 * 1_234.56)
+                .collect::<Vec<_>>();
+
+            for include_decimals in [false, true] {
+                let expected = numbers
+                    .iter()
+                    .map(|number| cardinal_converter(*number, include_decimals))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    cardinal_batch_converter(&numbers, include_decimals),
+                    expected
+                );
+            }
+        }
+
+        #[test]
+        fn test_empty() {
+            assert!(cardinal_batch_converter(&[], false).is_empty());
+        }
+    }
+
+    mod ordinal {
+        use super::*;
+
+        #[test]
+        fn test_same_as_ordinal_converter() {
+            let numbers = (0..2_000)
+                .chain([1_000_008, 6_000_800, 1_000_000_001])
+                .collect::<Vec<_>>();
+
+            for options in [None, Some(Options::new(true, true))] {
+                let expected = numbers
+                    .iter()
+                    .map(|number| ordinal_converter(*number, options))
+                    .collect::<Vec<_>>();
+                assert_eq!(ordinal_batch_converter(&numbers, options), expected);
+            }
+        }
+    }
+
+    mod parser {
+        use super::*;
+
+        #[test]
+        fn test_same_as_italian_converter() {
+            let words = (0..2_000)
+                .map(|number| cardinal_converter(number as f64, false).unwrap())
+                .chain(["prima", "XX°", "bla", ""].map(String::from))
+                .collect::<Vec<_>>();
+
+            let expected = words
+                .iter()
+                .map(|word| italian_converter(word.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(italian_batch_converter(&words), expected);
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_errors_do_not_stop_the_batch() {
+            assert_eq!(
+                ordinal_batch_converter(&[1_000_000_000_000, 2], None),
+                vec![
+                    Err("greater than 999.999.999.999"),
+                    Ok(String::from("secondo"))
+                ]
+            );
+            assert_eq!(
+                italian_batch_converter(&["bla", "Mi", "tre"]),
+                vec![
+                    Err("invalid word number"),
+                    Err("invalid word number"),
+                    Ok(3)
+                ]
+            );
+        }
+    }
+}
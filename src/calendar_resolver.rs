@@ -0,0 +1,415 @@
+use crate::{italian_number_parser, ItalianKind, MONTHS, WEEKDAYS};
+
+const ARTICLES: [&str; 6] = ["il", "lo", "la", "l'", "gli", "le"];
+const TYPOGRAPHIC_APOSTROPHE: char = '’';
+const QUARTERS: [u8; 4] = [1, 4, 7, 10];
+const SEMESTERS: [u8; 2] = [1, 7];
+
+/// A date of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl CalendarDate {
+    /// Creates a new `CalendarDate` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year, starting from 1.
+    /// * `month` - The month, from 1 (gennaio) to 12 (dicembre).
+    /// * `day` - The day of the month, starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use italian_numbers::CalendarDate;
+    ///
+    /// let date = CalendarDate::new(2024, 10, 8);
+    /// assert!(date.is_ok());
+    ///
+    /// let date = CalendarDate::new(2023, 2, 29);
+    /// assert_eq!(Err("invalid day"), date);
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Result<CalendarDate, &'static str> {
+        if year < 1 {
+            return Err("lower than 1");
+        }
+        if !(1..=12).contains(&month) {
+            return Err("invalid month");
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Err("invalid day");
+        }
+
+        Ok(CalendarDate { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 (gennaio) to 12 (dicembre).
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the day of the week, from 0 (lunedì) to 6 (domenica).
+/// The Gregorian calendar repeats every 400 years, so the year is reduced to that cycle first.
+fn weekday(year: i32, month: u8, day: u8) -> u8 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+    let year = if month < 3 { year - 1 } else { year } % 400;
+    let sunday_based =
+        (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day as i32) % 7;
+
+    ((sunday_based + 6) % 7) as u8
+}
+
+fn strip_accent(word: &str) -> String {
+    word.trim_end_matches('\'').replace(['ì', 'í'], "i")
+}
+
+fn get_weekday(word: &str) -> Result<u8, &'static str> {
+    let word = strip_accent(word);
+
+    WEEKDAYS
+        .iter()
+        .position(|&weekday| strip_accent(weekday) == word)
+        .map(|index| index as u8)
+        .ok_or("invalid weekday")
+}
+
+fn get_month(word: &str) -> Result<u8, &'static str> {
+    MONTHS
+        .iter()
+        .position(|&month| month == word)
+        .map(|index| index as u8 + 1)
+        .ok_or("invalid month")
+}
+
+fn get_months(words: &[&str], month: Option<u8>) -> Result<Vec<u8>, &'static str> {
+    match words {
+        [] | ["del", "mese"] | ["di", "ogni", "mese"] => match month {
+            Some(month) if !(1..=12).contains(&month) => Err("invalid month"),
+            Some(month) => Ok(vec![month]),
+            None => Ok((1..=12).collect()),
+        },
+        ["di", "ogni", "trimestre"] => Ok(QUARTERS.to_vec()),
+        ["di", "ogni", "semestre"] => Ok(SEMESTERS.to_vec()),
+        ["di", name] | ["del", "mese", "di", name] => Ok(vec![get_month(name)?]),
+        _ => Err("invalid expression"),
+    }
+}
+
+fn resolve(year: i32, month: u8, weekday_index: u8, ordinal: Option<u8>) -> Option<CalendarDate> {
+    let first_weekday = weekday(year, month, 1);
+    let first_day = 1 + (weekday_index + 7 - first_weekday) % 7;
+    let last_day = days_in_month(year, month);
+
+    let day = match ordinal {
+        Some(ordinal) => first_day + (ordinal - 1) * 7,
+        None => first_day + (last_day - first_day) / 7 * 7,
+    };
+
+    if day > last_day {
+        return None;
+    }
+
+    Some(CalendarDate { year, month, day })
+}
+
+/// Resolves an Italian ordinal calendar expression to the dates it refers to
+///
+/// # Arguments
+///
+/// * `expression` - The expression to resolve, e.g. "il terzo venerdì del mese".
+/// * `year` - The year in which the dates are computed.
+/// * `month` - The month used when the expression refers to "il mese". If `None`, every month of the year is used.
+///
+/// The ordinal can be any masculine or feminine ordinal word understood by `italian_number_parser` (e.g. "primo",
+/// "prima", "quinto"), or "ultimo"/"ultima" for the last occurrence. Cardinals like "tre" are rejected. Expressions naming a month ("di maggio") ignore the `month` argument,
+/// while "di ogni trimestre" and "di ogni semestre" refer to the first month of each quarter and semester.
+/// Months where the requested occurrence does not exist are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{calendar_resolver, CalendarDate};
+///
+/// let result = calendar_resolver("il secondo martedì di ottobre", 2024, None);
+/// assert_eq!(Ok(vec![CalendarDate::new(2024, 10, 8).unwrap()]), result);
+///
+/// let result = calendar_resolver("l'ultima domenica di maggio", 2024, None);
+/// assert_eq!(Ok(vec![CalendarDate::new(2024, 5, 26).unwrap()]), result);
+///
+/// let result = calendar_resolver("il terzo venerdì del mese", 2024, Some(3));
+/// assert_eq!(Ok(vec![CalendarDate::new(2024, 3, 15).unwrap()]), result);
+///
+/// let result = calendar_resolver("il primo lunedì di ogni trimestre", 2024, None);
+/// assert_eq!(
+///     Ok(vec![
+///         CalendarDate::new(2024, 1, 1).unwrap(),
+///         CalendarDate::new(2024, 4, 1).unwrap(),
+///         CalendarDate::new(2024, 7, 1).unwrap(),
+///         CalendarDate::new(2024, 10, 7).unwrap(),
+///     ]),
+///     result
+/// );
+/// ```
+pub fn calendar_resolver(
+    expression: &str,
+    year: i32,
+    month: Option<u8>,
+) -> Result<Vec<CalendarDate>, &'static str> {
+    if year < 1 {
+        return Err("lower than 1");
+    }
+
+    let escaped_expression = expression
+        .to_lowercase()
+        .replace(TYPOGRAPHIC_APOSTROPHE, "'")
+        .replace('\'', "' ");
+    let mut words = escaped_expression.split_whitespace().collect::<Vec<&str>>();
+
+    if words.first().is_some_and(|word| ARTICLES.contains(word)) {
+        words.remove(0);
+    }
+
+    if words.len() < 2 {
+        return Err("invalid expression");
+    }
+
+    let ordinal = match words[0] {
+        "ultimo" | "ultima" => None,
+        word => match italian_number_parser(word) {
+            Ok(number)
+                if number.kind() == ItalianKind::Ordinal && (1..=5).contains(&number.value()) =>
+            {
+                Some(number.value() as u8)
+            }
+            _ => return Err("invalid ordinal"),
+        },
+    };
+    let weekday_index = get_weekday(words[1])?;
+
+    let dates = get_months(&words[2..], month)?
+        .into_iter()
+        .filter_map(|month| resolve(year, month, weekday_index, ordinal))
+        .collect();
+
+    Ok(dates)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{calendar_resolver, CalendarDate};
+
+    fn date(year: i32, month: u8, day: u8) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    mod months {
+        use super::*;
+
+        #[test]
+        fn test_secondo_martedi_di_ottobre() {
+            assert_eq!(
+                calendar_resolver("il secondo martedì di ottobre", 2024, None),
+                Ok(vec![date(2024, 10, 8)])
+            );
+        }
+
+        #[test]
+        fn test_prima_domenica_di_settembre() {
+            assert_eq!(
+                calendar_resolver("la prima domenica di settembre", 2024, None),
+                Ok(vec![date(2024, 9, 1)])
+            );
+        }
+
+        #[test]
+        fn test_quarto_giovedi_di_novembre() {
+            assert_eq!(
+                calendar_resolver("il quarto giovedi di novembre", 2023, None),
+                Ok(vec![date(2023, 11, 23)])
+            );
+        }
+
+        #[test]
+        fn test_ultima_domenica_di_maggio() {
+            assert_eq!(
+                calendar_resolver("l'ultima domenica di maggio", 2024, None),
+                Ok(vec![date(2024, 5, 26)])
+            );
+        }
+
+        #[test]
+        fn test_ultimo_giovedi_di_febbraio_leap_year() {
+            assert_eq!(
+                calendar_resolver("l'ultimo giovedì di febbraio", 2024, None),
+                Ok(vec![date(2024, 2, 29)])
+            );
+        }
+
+        #[test]
+        fn test_typographic_apostrophe() {
+            assert_eq!(
+                calendar_resolver("l’ultima domenica di maggio", 2024, None),
+                Ok(vec![date(2024, 5, 26)])
+            );
+            assert_eq!(
+                calendar_resolver("il primo lunedi’ di maggio", 2024, None),
+                Ok(vec![date(2024, 5, 6)])
+            );
+        }
+
+        #[test]
+        fn test_large_year() {
+            // i32::MAX is 2047 modulo 400, and the calendar repeats every 400 years.
+            assert_eq!(
+                calendar_resolver("il primo lunedì di gennaio", i32::MAX, None),
+                Ok(vec![date(i32::MAX, 1, 7)])
+            );
+            assert_eq!(
+                calendar_resolver("il primo lunedì di gennaio", 2047, None),
+                Ok(vec![date(2047, 1, 7)])
+            );
+            assert_eq!(
+                calendar_resolver("l'ultimo lunedì di dicembre", i32::MAX, None),
+                Ok(vec![date(i32::MAX, 12, 30)])
+            );
+        }
+
+        #[test]
+        fn test_month_argument_is_ignored() {
+            assert_eq!(
+                calendar_resolver("il secondo martedì del mese di ottobre", 2024, Some(3)),
+                Ok(vec![date(2024, 10, 8)])
+            );
+        }
+    }
+
+    mod recurring {
+        use super::*;
+
+        #[test]
+        fn test_terzo_venerdi_del_mese() {
+            assert_eq!(
+                calendar_resolver("il terzo venerdì del mese", 2024, Some(3)),
+                Ok(vec![date(2024, 3, 15)])
+            );
+        }
+
+        #[test]
+        fn test_terzo_venerdi_of_every_month() {
+            let result = calendar_resolver("il terzo venerdì del mese", 2024, None).unwrap();
+            assert_eq!(result.len(), 12);
+            assert_eq!(result[0], date(2024, 1, 19));
+            assert_eq!(result[11], date(2024, 12, 20));
+        }
+
+        #[test]
+        fn test_primo_lunedi_di_ogni_trimestre() {
+            assert_eq!(
+                calendar_resolver("il primo lunedì di ogni trimestre", 2024, None),
+                Ok(vec![
+                    date(2024, 1, 1),
+                    date(2024, 4, 1),
+                    date(2024, 7, 1),
+                    date(2024, 10, 7)
+                ])
+            );
+        }
+
+        #[test]
+        fn test_quinto_lunedi_is_skipped() {
+            assert_eq!(
+                calendar_resolver("il quinto lunedì di ogni semestre", 2024, None),
+                Ok(vec![date(2024, 1, 29), date(2024, 7, 29)])
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid_ordinal() {
+            assert_eq!(
+                calendar_resolver("il sesto lunedì di maggio", 2024, None),
+                Err("invalid ordinal")
+            );
+        }
+
+        #[test]
+        fn test_cardinal() {
+            assert_eq!(
+                calendar_resolver("il tre lunedì di maggio", 2024, None),
+                Err("invalid ordinal")
+            );
+            assert_eq!(
+                calendar_resolver("il 3° lunedì di maggio", 2024, None),
+                Err("invalid ordinal")
+            );
+        }
+
+        #[test]
+        fn test_invalid_weekday() {
+            assert_eq!(
+                calendar_resolver("il primo giorno di maggio", 2024, None),
+                Err("invalid weekday")
+            );
+        }
+
+        #[test]
+        fn test_invalid_month() {
+            assert_eq!(
+                calendar_resolver("il primo lunedì di maggioo", 2024, None),
+                Err("invalid month")
+            );
+        }
+
+        #[test]
+        fn test_invalid_expression() {
+            assert_eq!(
+                calendar_resolver("il primo lunedì", 2024, Some(13)),
+                Err("invalid month")
+            );
+            assert_eq!(
+                calendar_resolver("primo", 2024, None),
+                Err("invalid expression")
+            );
+        }
+
+        #[test]
+        fn test_invalid_date() {
+            assert_eq!(CalendarDate::new(2023, 2, 29), Err("invalid day"));
+            assert_eq!(CalendarDate::new(0, 1, 1), Err("lower than 1"));
+        }
+    }
+}
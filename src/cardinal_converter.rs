@@ -212,7 +212,7 @@ pub fn cardinal_converter(number: f64, include_decimals: bool) -> Result<String,
 
     if include_decimals {
         let decimals = ((abs_number * 100.0).floor() % 100.0) as u64;
-        result = format!("{}/{}", result, format!("{:02}", decimals));
+        result = format!("{}/{:02}", result, decimals);
     }
 
     Ok(format!("{}{}", sign, result))
@@ -391,8 +391,6 @@ mod tests {
     }
 
     mod min_max {
-        use std::f64::INFINITY;
-
         use super::*;
 
         #[test]
@@ -413,7 +411,7 @@ mod tests {
         #[test]
         fn test_infinity() {
             assert_eq!(
-                cardinal_converter(INFINITY, false),
+                cardinal_converter(f64::INFINITY, false),
                 Ok(String::from("infinito"))
             )
         }
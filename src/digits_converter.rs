@@ -0,0 +1,400 @@
+use crate::cardinal_converter;
+
+pub(crate) const THOUSANDS_SEPARATOR: char = '.';
+pub(crate) const DECIMAL_SEPARATOR: char = ',';
+pub(crate) const MINUS_SIGNS: [char; 2] = ['-', '−'];
+
+/// How `digits_formatter` separates groups of three digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitGrouping {
+    /// 1.234.567, the usual Italian style.
+    Dot,
+    /// 1 234 567 with no-break spaces (U+00A0).
+    Space,
+    /// 1 234 567 with narrow no-break spaces (U+202F), the ISO style.
+    ThinSpace,
+    /// 1234567.
+    None,
+}
+
+impl DigitGrouping {
+    fn separator(&self) -> Option<char> {
+        match self {
+            DigitGrouping::Dot => Some(THOUSANDS_SEPARATOR),
+            DigitGrouping::Space => Some('\u{a0}'),
+            DigitGrouping::ThinSpace => Some('\u{202f}'),
+            DigitGrouping::None => None,
+        }
+    }
+}
+
+const SEPARATORS: [DigitGrouping; 3] = [
+    DigitGrouping::Dot,
+    DigitGrouping::Space,
+    DigitGrouping::ThinSpace,
+];
+
+/// A number read by `digits_parser`, keeping its decimal digits as written.
+pub(crate) struct Digits<'a> {
+    pub(crate) is_negative: bool,
+    pub(crate) integer: u64,
+    pub(crate) decimals: &'a str,
+}
+
+/// Reads an integer part written without separators, or with the same separator between all groups of three digits.
+pub(crate) fn integer_parser(digits: &str) -> Option<u64> {
+    let separator = SEPARATORS
+        .iter()
+        .filter_map(DigitGrouping::separator)
+        .find(|separator| digits.contains(*separator));
+    let groups = match separator {
+        Some(separator) => digits.split(separator).collect::<Vec<_>>(),
+        None => vec![digits],
+    };
+
+    let is_grouped = groups.len() > 1;
+    let is_valid = groups.iter().enumerate().all(|(index, group)| {
+        let length_is_valid = match (is_grouped, index) {
+            (false, _) => !group.is_empty(),
+            (true, 0) => (1..=3).contains(&group.len()),
+            (true, _) => group.len() == 3,
+        };
+        length_is_valid && group.chars().all(|digit| digit.is_ascii_digit())
+    });
+    if !is_valid {
+        return None;
+    }
+
+    groups.concat().parse().ok()
+}
+
+/// Separates groups of three digits in a string of ASCII digits, so that integers of any size can be grouped.
+pub(crate) fn group_digits(digits: &str, grouping: DigitGrouping) -> String {
+    let Some(separator) = grouping.separator() else {
+        return digits.to_string();
+    };
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * separator.len_utf8());
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+pub(crate) fn digits_parser(text: &str) -> Result<Digits<'_>, &'static str> {
+    let text = text.trim();
+    let (is_negative, text) = match text.strip_prefix(MINUS_SIGNS) {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+
+    let (integer, decimals) = match text.split_once(DECIMAL_SEPARATOR) {
+        Some((_, "")) => return Err("invalid digit number"),
+        Some((integer, decimals)) => (integer, decimals),
+        None => (text, ""),
+    };
+    if !decimals.chars().all(|digit| digit.is_ascii_digit()) {
+        return Err("invalid digit number");
+    }
+    let integer = integer_parser(integer).ok_or("invalid digit number")?;
+
+    Ok(Digits {
+        is_negative,
+        integer,
+        decimals,
+    })
+}
+
+/// Converts a number to the Italian digit format, with a decimal comma and grouped thousands
+///
+/// # Arguments
+///
+/// * `number` - The number to convert.
+/// * `decimals` - The decimal digits to write, rounding the number to the nearest, ties to even.
+/// * `grouping` - How groups of three digits are separated.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{digits_formatter, DigitGrouping};
+///
+/// let result = digits_formatter(1_234_567.891, 2, DigitGrouping::Dot);
+/// assert_eq!("1.234.567,89", result);
+///
+/// let result = digits_formatter(-1_234.6, 0, DigitGrouping::ThinSpace);
+/// assert_eq!("-1\u{202f}235", result);
+/// ```
+pub fn digits_formatter(number: f64, decimals: u8, grouping: DigitGrouping) -> String {
+    let formatted = format!("{:.*}", decimals as usize, number.abs());
+    let (integer, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let is_zero = formatted.chars().all(|digit| digit == '0' || digit == '.');
+    let sign = if number.is_sign_negative() && !is_zero {
+        "-"
+    } else {
+        ""
+    };
+    let integer = group_digits(integer, grouping);
+
+    if fraction.is_empty() {
+        return format!("{}{}", sign, integer);
+    }
+
+    format!("{}{}{}{}", sign, integer, DECIMAL_SEPARATOR, fraction)
+}
+
+/// Converts a number in the Italian digit format to its value
+///
+/// # Arguments
+///
+/// * `text` - The number to convert, like "1.234.567,89". Thousands are grouped by dots, no-break spaces or narrow
+///   no-break spaces, always the same one in groups of three digits, or not grouped at all. The decimal separator
+///   is a comma.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::digits_converter;
+///
+/// let result = digits_converter("1.234.567,89");
+/// assert_eq!(Ok(1_234_567.89), result);
+///
+/// let result = digits_converter("1\u{202f}234,5");
+/// assert_eq!(Ok(1_234.5), result);
+///
+/// let result = digits_converter("1,234.56");
+/// assert_eq!(Err("invalid digit number"), result);
+/// ```
+pub fn digits_converter(text: &str) -> Result<f64, &'static str> {
+    let digits = digits_parser(text)?;
+    let value = format!("{}.{}0", digits.integer, digits.decimals)
+        .parse::<f64>()
+        .map_err(|_| "invalid digit number")?;
+
+    Ok(if digits.is_negative { -value } else { value })
+}
+
+/// Converts a number in the Italian digit format to its Italian cardinal representation
+///
+/// # Arguments
+///
+/// * `text` - The number to convert, in the format read by `digits_converter`.
+/// * `include_decimals` - If true, the cents are written after a slash, as `cardinal_converter` does. The digits
+///   beyond the cents are dropped.
+///
+/// The decimal digits are taken as written, so they are not affected by floating point rounding. With
+/// `include_decimals`, the result is the amount in words with the cents in digits, as written on cheques.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::digits_cardinal_converter;
+///
+/// let result = digits_cardinal_converter("1.234,50", true);
+/// assert_eq!(Ok(String::from("milleduecentotrentaquattro/50")), result);
+///
+/// let result = digits_cardinal_converter("-21", false);
+/// assert_eq!(Ok(String::from("meno ventuno")), result);
+/// ```
+pub fn digits_cardinal_converter(
+    text: &str,
+    include_decimals: bool,
+) -> Result<String, &'static str> {
+    let digits = digits_parser(text)?;
+    let mut word = cardinal_converter(digits.integer as f64, false)?;
+
+    let cents = format!("{:0<2}", digits.decimals);
+    let cents = &cents[..2];
+    if include_decimals {
+        word = format!("{}/{}", word, cents);
+    }
+
+    let is_zero = digits.integer == 0 && (!include_decimals || cents == "00");
+    if digits.is_negative && !is_zero {
+        word = format!("meno {}", word);
+    }
+
+    Ok(word)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{digits_cardinal_converter, digits_converter, digits_formatter, DigitGrouping};
+
+    mod formatter {
+        use super::*;
+
+        #[test]
+        fn test_groupings() {
+            assert_eq!(
+                digits_formatter(1_234_567.0, 0, DigitGrouping::Dot),
+                "1.234.567"
+            );
+            assert_eq!(
+                digits_formatter(1_234_567.0, 0, DigitGrouping::Space),
+                "1\u{a0}234\u{a0}567"
+            );
+            assert_eq!(
+                digits_formatter(1_234_567.0, 0, DigitGrouping::None),
+                "1234567"
+            );
+            assert_eq!(digits_formatter(999.0, 0, DigitGrouping::Dot), "999");
+        }
+
+        #[test]
+        fn test_above_u64() {
+            assert_eq!(
+                digits_formatter(1e20, 0, DigitGrouping::Dot),
+                "100.000.000.000.000.000.000"
+            );
+            assert_eq!(
+                digits_formatter(u64::MAX as f64, 2, DigitGrouping::Dot),
+                "18.446.744.073.709.551.616,00"
+            );
+            assert_eq!(
+                digits_formatter(-1e21, 0, DigitGrouping::ThinSpace),
+                "-1\u{202f}000\u{202f}000\u{202f}000\u{202f}000\u{202f}000\u{202f}000\u{202f}000"
+            );
+            let text = digits_formatter(f64::MAX, 0, DigitGrouping::Dot);
+            assert!(text.starts_with("179.769.313.486.231.570."), "{}", text);
+        }
+
+        #[test]
+        fn test_decimals() {
+            assert_eq!(digits_formatter(1_234.5, 2, DigitGrouping::Dot), "1.234,50");
+            assert_eq!(digits_formatter(0.125, 1, DigitGrouping::Dot), "0,1");
+            assert_eq!(digits_formatter(999.999, 2, DigitGrouping::Dot), "1.000,00");
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(
+                digits_formatter(-1_234.5, 1, DigitGrouping::Dot),
+                "-1.234,5"
+            );
+            assert_eq!(digits_formatter(-0.001, 2, DigitGrouping::Dot), "0,00");
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let numbers = [0.0, 1.5, 999.99, 1_000.0, 1_234_567.89, -42.1];
+
+            for number in numbers {
+                for grouping in [
+                    DigitGrouping::Dot,
+                    DigitGrouping::Space,
+                    DigitGrouping::ThinSpace,
+                    DigitGrouping::None,
+                ] {
+                    let text = digits_formatter(number, 2, grouping);
+                    assert_eq!(digits_converter(&text), Ok(number), "{}", text);
+                }
+            }
+        }
+    }
+
+    mod converter {
+        use super::*;
+
+        #[test]
+        fn test_grouped() {
+            assert_eq!(digits_converter("1.234.567,89"), Ok(1_234_567.89));
+            assert_eq!(digits_converter("1\u{a0}234"), Ok(1_234.0));
+            assert_eq!(digits_converter("12.345"), Ok(12_345.0));
+        }
+
+        #[test]
+        fn test_not_grouped() {
+            assert_eq!(digits_converter("1234567,89"), Ok(1_234_567.89));
+            assert_eq!(digits_converter("0,5"), Ok(0.5));
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(digits_converter("-1.234,5"), Ok(-1_234.5));
+            assert_eq!(digits_converter("−3"), Ok(-3.0));
+        }
+    }
+
+    mod cardinal {
+        use super::*;
+
+        #[test]
+        fn test_words() {
+            assert_eq!(
+                digits_cardinal_converter("1.234.567", false),
+                Ok(String::from(
+                    "un milione e duecentotrentaquattromilacinquecentosessantasette"
+                ))
+            );
+            assert_eq!(
+                digits_cardinal_converter("21,5", true),
+                Ok(String::from("ventuno/50"))
+            );
+            assert_eq!(
+                digits_cardinal_converter("0,29", true),
+                Ok(String::from("zero/29"))
+            );
+            assert_eq!(
+                digits_cardinal_converter("3,999", true),
+                Ok(String::from("tre/99"))
+            );
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(
+                digits_cardinal_converter("-0,50", true),
+                Ok(String::from("meno zero/50"))
+            );
+            assert_eq!(
+                digits_cardinal_converter("-0,50", false),
+                Ok(String::from("zero"))
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid() {
+            let texts = [
+                "",
+                "1,234.56",
+                "1.23",
+                "1.2345",
+                "1.234\u{a0}567",
+                ".123",
+                "1,",
+                ",5",
+                "1,2,3",
+                "12a",
+                "1 234",
+            ];
+
+            for text in texts {
+                assert_eq!(
+                    digits_converter(text),
+                    Err("invalid digit number"),
+                    "{}",
+                    text
+                );
+            }
+        }
+
+        #[test]
+        fn test_greater_than_999_999_999_999() {
+            assert_eq!(
+                digits_cardinal_converter("1.000.000.000.000", false),
+                Err("greater than 999.999.999.999")
+            );
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use crate::digits_converter::{
+    group_digits, integer_parser, DECIMAL_SEPARATOR, MINUS_SIGNS, THOUSANDS_SEPARATOR,
+};
+use crate::DigitGrouping;
+
+/// The multipliers written after the digits, with their exponent. Only "mila" is glued to the digits.
+const MULTIPLIERS: [(&str, u32); 9] = [
+    ("mila", 3),
+    ("milione", 6),
+    ("milioni", 6),
+    ("mln", 6),
+    ("mln.", 6),
+    ("miliardo", 9),
+    ("miliardi", 9),
+    ("mld", 9),
+    ("mld.", 9),
+];
+
+/// The punctuation that can follow a number, ending it.
+const PUNCTUATION: [char; 9] = [',', '.', ';', ':', '!', '?', ')', '"', '»'];
+
+/// Options for `hybrid_formatter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HybridOptions {
+    decimals: u8,
+    abbreviated: bool,
+}
+
+impl HybridOptions {
+    /// Creates a new `HybridOptions` instance with the specified settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The most decimal digits written before the multiplier, e.g. 1 for "2,5 miliardi", up to 9.
+    /// * `abbreviated` - Set to `true` to write "mln" and "mld" instead of "milioni" and "miliardi".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use italian_numbers::HybridOptions;
+    ///
+    /// let options = HybridOptions::new(1, true); // "1,2 mln"
+    /// ```
+    pub fn new(decimals: u8, abbreviated: bool) -> HybridOptions {
+        HybridOptions {
+            decimals,
+            abbreviated,
+        }
+    }
+
+    /// Returns the most decimal digits written before the multiplier.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns `true` if "mln" and "mld" are written instead of "milioni" and "miliardi".
+    pub fn is_abbreviated(&self) -> bool {
+        self.abbreviated
+    }
+}
+
+/// Converts a number written with digits and an Italian multiplier to its exact value
+///
+/// # Arguments
+///
+/// * `text` - The number to convert, like "3 milioni", "2,5 miliardi", "20mila", "1,2 mln" or "3 mld". The digits
+///   use the Italian decimal comma and may group thousands with dots. The number ends at the first word that is not
+///   a multiplier or at punctuation, so "3 milioni di euro" is read as "3 milioni", "3.000 euro" as 3000 and
+///   "3 milioni, 2 mila" as "3 milioni".
+///
+/// The decimal digits must fit in the multiplier, so "1,5 mila" is 1500 while "1,2345 mila" is not a whole number.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::hybrid_converter;
+///
+/// let result = hybrid_converter("2,5 miliardi");
+/// assert_eq!(Ok(2_500_000_000), result);
+///
+/// let result = hybrid_converter("20mila persone");
+/// assert_eq!(Ok(20_000), result);
+///
+/// let result = hybrid_converter("1.500 milioni di euro");
+/// assert_eq!(Ok(1_500_000_000), result);
+///
+/// let result = hybrid_converter("3 euro.");
+/// assert_eq!(Ok(3), result);
+///
+/// let result = hybrid_converter("1,2345 mila");
+/// assert_eq!(Err("not a whole number"), result);
+/// ```
+pub fn hybrid_converter(text: &str) -> Result<i64, &'static str> {
+    let text = text.trim();
+    let (is_negative, text) = match text.strip_prefix(MINUS_SIGNS) {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+
+    let digits_end = text
+        .find(|letter: char| {
+            !letter.is_ascii_digit() && letter != THOUSANDS_SEPARATOR && letter != DECIMAL_SEPARATOR
+        })
+        .unwrap_or(text.len());
+    let (mut digits, mut rest) = text.split_at(digits_end);
+    if let Some(last) = digits.strip_suffix(PUNCTUATION) {
+        if is_word_end(rest) {
+            (digits, rest) = (last, "");
+        }
+    }
+
+    let exponent = match rest.strip_prefix(MULTIPLIERS[0].0) {
+        Some(after) if is_word_end(after) => MULTIPLIERS[0].1,
+        _ if !is_word_end(rest) => return Err("invalid hybrid number"),
+        _ if rest.starts_with(PUNCTUATION) => 0,
+        _ => match rest.split_whitespace().next().map(str::to_lowercase) {
+            Some(word) => multiplier_exponent(&word)
+                .or_else(|| multiplier_exponent(word.trim_end_matches(PUNCTUATION)))
+                .unwrap_or(0),
+            None => 0,
+        },
+    };
+
+    let (integer, decimals) = match digits.split_once(DECIMAL_SEPARATOR) {
+        Some((integer, decimals)) => (integer, decimals),
+        None => (digits, ""),
+    };
+    let integer = integer_parser(integer)
+        .and_then(|integer| i64::try_from(integer).ok())
+        .ok_or("invalid hybrid number")?;
+    if digits.ends_with(DECIMAL_SEPARATOR) || !decimals.chars().all(|digit| digit.is_ascii_digit())
+    {
+        return Err("invalid hybrid number");
+    }
+
+    let decimals = decimals.trim_end_matches('0');
+    if decimals.len() > exponent as usize {
+        return Err("not a whole number");
+    }
+
+    let fraction = match decimals {
+        "" => 0,
+        _ => decimals
+            .parse::<i64>()
+            .map_err(|_| "invalid hybrid number")?,
+    };
+    let value = integer
+        .checked_mul(10_i64.pow(exponent))
+        .and_then(|value| {
+            value.checked_add(fraction * 10_i64.pow(exponent - decimals.len() as u32))
+        })
+        .ok_or("invalid hybrid number")?;
+
+    Ok(if is_negative { -value } else { value })
+}
+
+/// Returns `true` if nothing but punctuation comes before the end of the text or the next space.
+fn is_word_end(text: &str) -> bool {
+    let text = text.trim_start_matches(PUNCTUATION);
+    text.is_empty() || text.starts_with(char::is_whitespace)
+}
+
+fn multiplier_exponent(word: &str) -> Option<u32> {
+    MULTIPLIERS
+        .iter()
+        .find(|(multiplier, _)| *multiplier == word)
+        .map(|(_, exponent)| *exponent)
+}
+
+/// Converts a number to digits and an Italian multiplier, as written in newspapers
+///
+/// # Arguments
+///
+/// * `number` - The number to convert.
+/// * `options` - The decimal digits to keep and whether to abbreviate the multipliers.
+///
+/// The multiplier is the largest of "mila", "milioni" and "miliardi" not greater than the number, and the digits
+/// are rounded half away from zero, dropping trailing zeros. Numbers lower than 1000 are written with digits only.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{hybrid_formatter, HybridOptions};
+///
+/// let result = hybrid_formatter(2_480_000_000, HybridOptions::new(1, false));
+/// assert_eq!("2,5 miliardi", result);
+///
+/// let result = hybrid_formatter(1_234_567, HybridOptions::new(1, true));
+/// assert_eq!("1,2 mln", result);
+///
+/// let result = hybrid_formatter(20_000, HybridOptions::default());
+/// assert_eq!("20mila", result);
+///
+/// let result = hybrid_formatter(1_000_000, HybridOptions::default());
+/// assert_eq!("1 milione", result);
+/// ```
+pub fn hybrid_formatter(number: i64, options: HybridOptions) -> String {
+    let sign = if number < 0 { "-" } else { "" };
+    let absolute = number.unsigned_abs() as u128;
+    let precision = options.decimals.min(9) as usize;
+    let scale = 10_u128.pow(precision as u32);
+
+    let mut exponent = [9, 6, 3]
+        .into_iter()
+        .find(|exponent| absolute >= 10_u128.pow(*exponent))
+        .unwrap_or(0);
+    if exponent == 0 {
+        return format!("{}{}", sign, absolute);
+    }
+
+    let mut scaled = (absolute * scale + 10_u128.pow(exponent) / 2) / 10_u128.pow(exponent);
+    if scaled >= 1_000 * scale && exponent < 9 {
+        exponent += 3;
+        scaled = (absolute * scale + 10_u128.pow(exponent) / 2) / 10_u128.pow(exponent);
+    }
+
+    let integer = (scaled / scale) as u64;
+    let decimals = format!("{:0width$}", scaled % scale, width = precision);
+    let decimals = decimals.trim_end_matches('0');
+    let mut digits = group_digits(&integer.to_string(), DigitGrouping::Dot);
+    if !decimals.is_empty() {
+        digits.push(DECIMAL_SEPARATOR);
+        digits.push_str(decimals);
+    }
+
+    let is_one = digits == "1";
+    let multiplier = match (exponent, options.abbreviated, is_one) {
+        (3, _, true) => return format!("{}{}", sign, group_digits("1000", DigitGrouping::Dot)),
+        (3, _, false) => return format!("{}{}mila", sign, digits),
+        (6, true, _) => "mln",
+        (6, false, true) => "milione",
+        (6, false, false) => "milioni",
+        (_, true, _) => "mld",
+        (_, false, true) => "miliardo",
+        (_, false, false) => "miliardi",
+    };
+
+    format!("{}{} {}", sign, digits, multiplier)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{hybrid_converter, hybrid_formatter, HybridOptions};
+
+    mod converter {
+        use super::*;
+
+        #[test]
+        fn test_multipliers() {
+            assert_eq!(hybrid_converter("3 milioni"), Ok(3_000_000));
+            assert_eq!(hybrid_converter("1 milione"), Ok(1_000_000));
+            assert_eq!(hybrid_converter("3 mld"), Ok(3_000_000_000));
+            assert_eq!(hybrid_converter("3 Mld."), Ok(3_000_000_000));
+            assert_eq!(hybrid_converter("1 miliardo"), Ok(1_000_000_000));
+            assert_eq!(hybrid_converter("20mila"), Ok(20_000));
+            assert_eq!(hybrid_converter("20 mila"), Ok(20_000));
+        }
+
+        #[test]
+        fn test_decimals() {
+            assert_eq!(hybrid_converter("2,5 miliardi"), Ok(2_500_000_000));
+            assert_eq!(hybrid_converter("1,2 mln"), Ok(1_200_000));
+            assert_eq!(hybrid_converter("1,250 mila"), Ok(1_250));
+            assert_eq!(hybrid_converter("0,75 milioni"), Ok(750_000));
+        }
+
+        #[test]
+        fn test_grouping() {
+            assert_eq!(hybrid_converter("1.500 milioni"), Ok(1_500_000_000));
+            assert_eq!(hybrid_converter("1.234.567"), Ok(1_234_567));
+            assert_eq!(hybrid_converter("1234567"), Ok(1_234_567));
+        }
+
+        #[test]
+        fn test_trailing_words() {
+            assert_eq!(hybrid_converter("3 milioni di euro"), Ok(3_000_000));
+            assert_eq!(hybrid_converter("20mila persone"), Ok(20_000));
+            assert_eq!(hybrid_converter("3 euro"), Ok(3));
+            assert_eq!(hybrid_converter("3.000 euro"), Ok(3_000));
+            assert_eq!(hybrid_converter("3 milionari"), Ok(3));
+            assert_eq!(hybrid_converter("1,5 mila euro"), Ok(1_500));
+        }
+
+        #[test]
+        fn test_trailing_punctuation() {
+            assert_eq!(hybrid_converter("3 milioni,"), Ok(3_000_000));
+            assert_eq!(hybrid_converter("3 milioni, 2 mila"), Ok(3_000_000));
+            assert_eq!(hybrid_converter("3 mln."), Ok(3_000_000));
+            assert_eq!(hybrid_converter("20mila."), Ok(20_000));
+            assert_eq!(
+                hybrid_converter("(3 miliardi)"),
+                Err("invalid hybrid number")
+            );
+            assert_eq!(hybrid_converter("3 miliardi)"), Ok(3_000_000_000));
+            assert_eq!(hybrid_converter("3.000."), Ok(3_000));
+            assert_eq!(hybrid_converter("3.000, 5"), Ok(3_000));
+            assert_eq!(hybrid_converter("2,5,"), Err("not a whole number"));
+            assert_eq!(hybrid_converter("2, mila"), Ok(2));
+            assert_eq!(hybrid_converter("3; 4"), Ok(3));
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(hybrid_converter("-2,5 miliardi"), Ok(-2_500_000_000));
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let options = [
+                HybridOptions::new(0, false),
+                HybridOptions::new(1, false),
+                HybridOptions::new(3, true),
+            ];
+            let numbers = [
+                0,
+                999,
+                1_000,
+                1_500,
+                20_000,
+                999_999,
+                1_234_567,
+                2_500_000_000,
+            ];
+
+            for options in options {
+                for number in numbers {
+                    let text = hybrid_formatter(number, options);
+                    let value = hybrid_converter(&text).unwrap();
+                    assert_eq!(hybrid_formatter(value, options), text, "{}", number);
+                }
+            }
+        }
+    }
+
+    mod formatter {
+        use super::*;
+
+        #[test]
+        fn test_units() {
+            assert_eq!(hybrid_formatter(999, HybridOptions::new(1, false)), "999");
+            assert_eq!(
+                hybrid_formatter(1_000, HybridOptions::new(1, false)),
+                "1.000"
+            );
+            assert_eq!(
+                hybrid_formatter(1_500, HybridOptions::new(1, false)),
+                "1,5mila"
+            );
+            assert_eq!(
+                hybrid_formatter(20_000, HybridOptions::new(1, true)),
+                "20mila"
+            );
+        }
+
+        #[test]
+        fn test_millions_and_billions() {
+            assert_eq!(
+                hybrid_formatter(3_000_000, HybridOptions::default()),
+                "3 milioni"
+            );
+            assert_eq!(
+                hybrid_formatter(1_500_000, HybridOptions::new(2, false)),
+                "1,5 milioni"
+            );
+            assert_eq!(
+                hybrid_formatter(3_000_000_000, HybridOptions::new(0, true)),
+                "3 mld"
+            );
+            assert_eq!(
+                hybrid_formatter(1_234_000_000_000, HybridOptions::default()),
+                "1.234 miliardi"
+            );
+        }
+
+        #[test]
+        fn test_rounding() {
+            assert_eq!(
+                hybrid_formatter(1_250_000, HybridOptions::new(1, false)),
+                "1,3 milioni"
+            );
+            assert_eq!(
+                hybrid_formatter(1_240_000, HybridOptions::new(1, false)),
+                "1,2 milioni"
+            );
+            assert_eq!(
+                hybrid_formatter(999_999_999, HybridOptions::new(1, false)),
+                "1 miliardo"
+            );
+            assert_eq!(
+                hybrid_formatter(999_600, HybridOptions::new(0, false)),
+                "1 milione"
+            );
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(
+                hybrid_formatter(-2_500_000_000, HybridOptions::new(1, false)),
+                "-2,5 miliardi"
+            );
+            assert_eq!(
+                hybrid_formatter(1_500, HybridOptions::new(u8::MAX, false)),
+                "1,5mila"
+            );
+            assert_eq!(
+                hybrid_formatter(i64::MIN, HybridOptions::default()),
+                "-9.223.372.037 miliardi"
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_not_a_whole_number() {
+            assert_eq!(hybrid_converter("2,5"), Err("not a whole number"));
+            assert_eq!(hybrid_converter("1,2345 mila"), Err("not a whole number"));
+        }
+
+        #[test]
+        fn test_invalid() {
+            let texts = [
+                "",
+                "milioni",
+                "2.5 miliardi",
+                "1.23 mila",
+                "3milioni",
+                "20milano",
+                "2,5,1 mila",
+                "3euro",
+                "3,000.5",
+                "tre milioni",
+                "99999999999 miliardi",
+            ];
+
+            for text in texts {
+                assert_eq!(
+                    hybrid_converter(text),
+                    Err("invalid hybrid number"),
+                    "{}",
+                    text
+                );
+            }
+        }
+    }
+}
@@ -1,274 +1,11 @@
-use regex::Regex;
-
-use crate::{TENS, ZERO_TEN_ORDINALS};
-
-fn get_unit(word: &str) -> Result<u8, &'static str> {
-    match word {
-        "un" => Ok(1),
-        "uno" => Ok(1),
-        "due" => Ok(2),
-        "tre" => Ok(3),
-        "tré" => Ok(3),
-        "quattro" => Ok(4),
-        "cinque" => Ok(5),
-        "sei" => Ok(6),
-        "sette" => Ok(7),
-        "otto" => Ok(8),
-        "nove" => Ok(9),
-        "dieci" => Ok(10),
-        "undici" => Ok(11),
-        "dodici" => Ok(12),
-        "tredici" => Ok(13),
-        "quattordici" => Ok(14),
-        "quindici" => Ok(15),
-        "sedici" => Ok(16),
-        "diciassette" => Ok(17),
-        "diciotto" => Ok(18),
-        "diciannove" => Ok(19),
-        _ => Err("Not found"),
-    }
-}
-
-fn get_ten(word: &str) -> Result<u8, &'static str> {
-    match word {
-        "venti" => Ok(20),
-        "trenta" => Ok(30),
-        "quaranta" => Ok(40),
-        "cinquanta" => Ok(50),
-        "sessanta" => Ok(60),
-        "settanta" => Ok(70),
-        "ottanta" => Ok(80),
-        "novanta" => Ok(90),
-        _ => Err("Not found"),
-    }
-}
-
-fn tens_converter(word: &str) -> Result<u8, &'static str> {
-    if let Ok(unit) = get_unit(word) {
-        return Ok(unit);
-    }
-
-    let ten = TENS
-        .iter()
-        .find(|&ten| word.starts_with(&ten[..ten.len() - 1]))
-        .ok_or("Not found")?;
-
-    let result = get_ten(ten).unwrap();
-    if word == *ten {
-        return Ok(result);
-    }
-
-    let split_word = |separator| word.split(separator).collect::<Vec<&str>>();
-    let mut unit = split_word(ten);
-    if unit.len() < 2 {
-        unit = split_word(&&ten[..ten.len() - 1]);
-    }
-
-    let unit_word = &unit.get(1).unwrap_or(&"").to_string();
-
-    match get_unit(unit_word) {
-        Ok(value) => Ok(result + value),
-        Err(_) => Err("Not found"),
-    }
-}
-
-fn hundreds_converter(word: &str) -> Result<u16, &'static str> {
-    let mut split = word.split("cento");
-    let hundred = split.next().unwrap_or("");
-    let ten = split.next();
-
-    if ten.is_none() {
-        return tens_converter(hundred).map(|v| v as u16);
-    }
-
-    let mut result: u16;
-    if hundred.is_empty() {
-        result = 100;
-    } else {
-        match get_unit(hundred) {
-            Ok(v) => result = (v as u16) * 100,
-            Err(_) => return Err("Not found"),
-        }
-    }
-
-    let real_ten = ten.unwrap_or("");
-    if !real_ten.is_empty() {
-        let tens_word = if real_ten.starts_with("tt") {
-            format!("o{}", real_ten)
-        } else {
-            real_ten.to_string()
-        };
-
-        match tens_converter(&tens_word) {
-            Ok(v) => {
-                result += v as u16;
-            }
-            Err(_) => return Err("Not found"),
-        }
-    }
-
-    Ok(result)
-}
-
-fn numbers_calculator(word: &str) -> Result<i64, &'static str> {
-    // billions
-    let mut index = word.find("miliard");
-    if let Some(i) = index {
-        let sub = &word[i + 8..];
-        let mut rest = 0;
-        if !sub.is_empty() {
-            match numbers_calculator(sub) {
-                Ok(v) => rest = v,
-                Err(_) => return Err("not found"),
-            }
-        }
-
-        return match hundreds_converter(&word[0..i]) {
-            Ok(v) => Ok(v as i64 * 1_000_000_000 + rest),
-            Err(_) => Err("not found"),
-        };
-    }
-
-    // millions
-    index = word.find("milion");
-    if let Some(i) = index {
-        let sub = &word[i + 7..];
-        let mut rest = 0;
-        if !sub.is_empty() {
-            match numbers_calculator(sub) {
-                Ok(v) => rest = v,
-                Err(_) => return Err("not found"),
-            }
-        }
-
-        return match hundreds_converter(&word[0..i]) {
-            Ok(v) => Ok(v as i64 * 1_000_000 + rest),
-            Err(_) => Err("not found"),
-        };
-    }
-
-    // thounsands
-    index = word.find("mila");
-    if let Some(i) = index {
-        let sub = &word[i + 4..];
-        let mut rest = 0;
-        if !sub.is_empty() {
-            match numbers_calculator(sub) {
-                Ok(v) => rest = v,
-                Err(_) => return Err("not found"),
-            }
-        }
-
-        return match hundreds_converter(&word[0..i]) {
-            Ok(v) => Ok(v as i64 * 1_000 + rest),
-            Err(_) => Err("not found"),
-        };
-    }
-
-    index = word.find("mille");
-    if let Some(i) = index {
-        let sub = &word[i + 5..];
-        if sub.is_empty() {
-            return Ok(1_000);
-        }
-
-        return match hundreds_converter(&sub) {
-            Ok(v) => Ok(v as i64 + 1_000),
-            Err(_) => Err("not found"),
-        };
-    }
-
-    hundreds_converter(&word).map(|v| v as i64)
-}
-
-fn get_number_from_ordinal(ordinal: &str) -> String {
-    let mut cardinal = ordinal.to_string();
-    let re_decim = Regex::new("decim\\w$").unwrap();
-    let re_centesim = Regex::new("centesim\\w$").unwrap();
-    let re_millesim_one = Regex::new("^millesim\\w").unwrap();
-    let re_millesim_many = Regex::new("millesim\\w").unwrap();
-    let re_milionesim_one = Regex::new("^milionesim\\w").unwrap();
-    let re_milionesim_many = Regex::new("milionesim\\w").unwrap();
-    let re_miliardesim_one = Regex::new("^miliardesim\\w").unwrap();
-    let re_miliardesim_many = Regex::new("miliardesim\\w").unwrap();
-    let re_ventesim = Regex::new("ventesim\\w").unwrap();
-    let re_ntesim = Regex::new("ntesim\\w").unwrap();
-    let re_unesim = Regex::new("unesim\\w").unwrap();
-    let re_quattresim = Regex::new("quattresim\\w").unwrap();
-    let re_ottesim = Regex::new("ottesim\\w").unwrap();
-    let re_duesim = Regex::new("duesim\\w").unwrap();
-    let re_cinquesim = Regex::new("cinquesim\\w").unwrap();
-    let re_settesim = Regex::new("settesim\\w").unwrap();
-    let re_novesim = Regex::new("novesim\\w").unwrap();
-    let re_undicesim = Regex::new("undicesim\\w").unwrap();
-    let re_dodicesim = Regex::new("dodicesim\\w").unwrap();
-    let re_tredicesim = Regex::new("tredicesim\\w").unwrap();
-    let re_quattordicesim = Regex::new("quattordicesim\\w").unwrap();
-    let re_quindicesim = Regex::new("quindicesim\\w").unwrap();
-    let re_sedicesim = Regex::new("sedicesim\\w").unwrap();
-    let re_esim = Regex::new("esim\\w").unwrap();
-    let re_milione = Regex::new("^milione").unwrap();
-    let re_miliard = Regex::new("^miliard").unwrap();
-    let re_centuno = Regex::new("centuno").unwrap();
-    let re_centotto = Regex::new("centotto").unwrap();
-    let re_miliarduno = Regex::new("miliarduno").unwrap();
-    let re_miliardotto = Regex::new("miliardotto").unwrap();
-
-    cardinal = re_decim.replace_all(&cardinal, "dieci").to_string();
-    cardinal = re_centesim.replace_all(&cardinal, "cento").to_string();
-    cardinal = re_millesim_one.replace_all(&cardinal, "mille").to_string();
-    cardinal = re_millesim_many.replace_all(&cardinal, "mila").to_string();
-    cardinal = re_milionesim_one
-        .replace_all(&cardinal, "unmilione")
-        .to_string();
-    cardinal = re_milionesim_many
-        .replace_all(&cardinal, "milioni")
-        .to_string();
-    cardinal = re_miliardesim_one
-        .replace_all(&cardinal, "unmiliardo")
-        .to_string();
-    cardinal = re_miliardesim_many
-        .replace_all(&cardinal, "miliardi")
-        .to_string();
-    cardinal = re_ventesim.replace_all(&cardinal, "venti").to_string();
-    cardinal = re_ntesim.replace_all(&cardinal, "nta").to_string();
-    cardinal = re_unesim.replace_all(&cardinal, "uno").to_string();
-    cardinal = re_quattresim.replace_all(&cardinal, "quattro").to_string();
-    cardinal = re_ottesim.replace_all(&cardinal, "otto").to_string();
-    cardinal = re_duesim.replace_all(&cardinal, "due").to_string();
-    cardinal = re_cinquesim.replace_all(&cardinal, "cinque").to_string();
-    cardinal = re_settesim.replace_all(&cardinal, "sette").to_string();
-    cardinal = re_novesim.replace_all(&cardinal, "nove").to_string();
-    cardinal = re_undicesim.replace_all(&cardinal, "undici").to_string();
-    cardinal = re_dodicesim.replace_all(&cardinal, "dodici").to_string();
-    cardinal = re_tredicesim.replace_all(&cardinal, "tredici").to_string();
-    cardinal = re_quattordicesim
-        .replace_all(&cardinal, "quattordici")
-        .to_string();
-    cardinal = re_quindicesim
-        .replace_all(&cardinal, "quindici")
-        .to_string();
-    cardinal = re_sedicesim.replace_all(&cardinal, "sedici").to_string();
-    cardinal = re_esim.replace_all(&cardinal, "").to_string();
-    cardinal = re_milione.replace_all(&cardinal, "unmilione").to_string();
-    cardinal = re_miliard.replace_all(&cardinal, "unmiliardo").to_string();
-    cardinal = re_centuno.replace_all(&cardinal, "centouno").to_string();
-    cardinal = re_centotto.replace_all(&cardinal, "centootto").to_string();
-    cardinal = re_miliarduno
-        .replace_all(&cardinal, "miliardouno")
-        .to_string();
-    cardinal = re_miliardotto
-        .replace_all(&cardinal, "miliardootto")
-        .to_string();
-
-    cardinal
-}
+use crate::{italian_parser, ordinal_indicator_parser};
 
 /// Converts an Italian word representation to a number
 /// 
 /// # Arguments
 /// 
-/// * `word` - The Italian word to convert.
+/// * `word` - The Italian word to convert. Short ordinals with an indicator, like "1ª", "XX°" or "10mo", are read as
+///   by `ordinal_indicator_parser`.
 ///
 /// # Examples
 ///
@@ -292,55 +29,23 @@ fn get_number_from_ordinal(ordinal: &str) -> String {
 ///
 /// let result = italian_converter("quattrocentotredicesime".to_string());
 /// assert_eq!(Ok(413), result);
+///
+/// let result = italian_converter("2ª".to_string());
+/// assert_eq!(Ok(2), result);
 /// ```
 pub fn italian_converter(word: String) -> Result<i64, &'static str> {
-    let mut escaped_word = word.to_lowercase();
-    escaped_word = escaped_word.replace(" e ", "");
-    escaped_word = escaped_word.replace(" ", "");
-
-    let mut is_negative = false;
-    if escaped_word.starts_with("meno") {
-        is_negative = true;
-        escaped_word = escaped_word.replace("meno", "");
-    }
+    word_parser(&word)
+}
 
-    if escaped_word == "zero" {
-        return Ok(0);
+pub(crate) fn word_parser(word: &str) -> Result<i64, &'static str> {
+    if let Ok(number) = italian_parser(word) {
+        return Ok(number);
     }
 
-    if escaped_word.ends_with('a')
-        || escaped_word.ends_with('o')
-        || escaped_word.ends_with('e')
-        || escaped_word.ends_with('i')
-    {
-        let index = ZERO_TEN_ORDINALS
-            .iter()
-            .position(|&ord| escaped_word.starts_with(&ord[..ord.len() - 1]));
-        match index {
-            Some(index) => {
-                return Ok(index.try_into().unwrap());
-            }
-            None => {
-                let ends = vec![
-                    "esima", "esimo", "esime", "esimi", "decima", "decimo", "decime", "decimi",
-                ];
-                if ends.iter().any(|&end| escaped_word.ends_with(end)) {
-                    escaped_word = get_number_from_ordinal(&escaped_word);
-                }
-            }
-        }
-    }
-
-    match numbers_calculator(&escaped_word) {
-        Ok(result) => {
-            if is_negative {
-                return Ok(-result);
-            }
-
-            Ok(result)
-        }
-        Err(_) => Err("invalid word number"),
-    }
+    ordinal_indicator_parser(word)
+        .ok()
+        .and_then(|(number, _)| i64::try_from(number).ok())
+        .ok_or("invalid word number")
 }
 
 /* TESTS */
@@ -1411,6 +1116,122 @@ mod tests {
         }
     }
 
+    mod informal {
+        use super::*;
+
+        #[test]
+        fn test_half() {
+            assert_eq!(
+                italian_converter("un milione e mezzo".to_string()),
+                Ok(1_500_000)
+            );
+            assert_eq!(
+                italian_converter("tre miliardi e mezzo".to_string()),
+                Ok(3_500_000_000)
+            );
+            assert_eq!(
+                italian_converter("due milioni e mezza".to_string()),
+                Ok(2_500_000)
+            );
+        }
+
+        #[test]
+        fn test_conjunction_after_thousand() {
+            assert_eq!(italian_converter("mille e cento".to_string()), Ok(1_100));
+            assert_eq!(italian_converter("mille e uno".to_string()), Ok(1_001));
+            assert_eq!(
+                italian_converter("due milioni e due".to_string()),
+                Ok(2_000_002)
+            );
+        }
+
+        #[test]
+        fn test_feminine() {
+            assert_eq!(italian_converter("mille e una".to_string()), Ok(1_001));
+            assert_eq!(italian_converter("una".to_string()), Ok(1));
+            assert_eq!(italian_converter("ventuna".to_string()), Ok(21));
+            assert_eq!(italian_converter("centouna".to_string()), Ok(101));
+        }
+
+        #[test]
+        fn test_dozen() {
+            assert_eq!(italian_converter("una dozzina".to_string()), Ok(12));
+            assert_eq!(
+                italian_converter("una dozzina di migliaia".to_string()),
+                Ok(12_000)
+            );
+            assert_eq!(
+                italian_converter("tre dozzine di milioni".to_string()),
+                Ok(36_000_000)
+            );
+        }
+
+        #[test]
+        fn test_invalid() {
+            let words = [
+                "mezzo",
+                "tre e mezzo",
+                "un milione mezzo",
+                "un milione e mezzo e tre",
+                "dozzina",
+                "tre dozzina",
+                "una dozzine",
+                "una dozzina di",
+                "una dozzina di tre",
+                "tre di milioni",
+            ];
+
+            for word in words {
+                assert_eq!(
+                    italian_converter(word.to_string()),
+                    Err("invalid word number"),
+                    "{}",
+                    word
+                );
+            }
+        }
+    }
+
+    mod indicators {
+        use super::*;
+
+        #[test]
+        fn test_indicators() {
+            assert_eq!(italian_converter("1°".to_string()), Ok(1));
+            assert_eq!(italian_converter("1ª".to_string()), Ok(1));
+            assert_eq!(italian_converter("2i".to_string()), Ok(2));
+            assert_eq!(italian_converter("3e".to_string()), Ok(3));
+            assert_eq!(italian_converter("10mo".to_string()), Ok(10));
+            assert_eq!(italian_converter("2do".to_string()), Ok(2));
+            assert_eq!(italian_converter("8vo".to_string()), Ok(8));
+            assert_eq!(italian_converter("XX°".to_string()), Ok(20));
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(
+                italian_converter("1u".to_string()),
+                Err("invalid word number")
+            );
+            assert_eq!(
+                italian_converter("2mo".to_string()),
+                Err("invalid word number")
+            );
+        }
+
+        #[test]
+        fn test_words_starting_with_roman_letters() {
+            for word in ["Io", "Mi", "Ma", "Me", "Di", "Ci", "Vi", "Le"] {
+                assert_eq!(
+                    italian_converter(word.to_string()),
+                    Err("invalid word number"),
+                    "{}",
+                    word
+                );
+            }
+        }
+    }
+
     // mod mixed {
     //     use crate::ordinal_converter;
 
@@ -0,0 +1,348 @@
+use crate::italian_normalizer::normalize;
+use std::sync::OnceLock;
+
+use crate::italian_parser::{connector, lexicon, parse, Entry, Grammar, Token, ORDINAL_ENDINGS};
+
+const BEAM_WIDTH: usize = 16;
+
+/// A number read by `italian_fuzzy_converter`, with the spelling it was corrected to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItalianCorrection {
+    spelling: String,
+    value: i64,
+    confidence: f32,
+}
+
+impl ItalianCorrection {
+    /// Returns the corrected spelling of the number.
+    pub fn spelling(&self) -> &str {
+        &self.spelling
+    }
+
+    /// Returns the value of the number.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns how likely the correction is, from 0 to 1. An exact spelling has a confidence of 1.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+/// A way of reading the text so far: the grammar state, the edits made and the corrected spelling.
+#[derive(Clone)]
+struct Reading {
+    grammar: Grammar,
+    edits: usize,
+    words: usize,
+    spelling: String,
+}
+
+/// The optimal string alignment distance: insertions, deletions, substitutions and adjacent transpositions.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// The edits allowed for a word of the lexicon: one for short words, a third of the length for the others.
+fn max_edits(length: usize) -> usize {
+    if length <= 3 {
+        return 1;
+    }
+
+    (length + 1) / 3
+}
+
+fn prune(readings: &mut Vec<Reading>) {
+    readings.sort_by(|a, b| (a.edits, a.words, &a.spelling).cmp(&(b.edits, b.words, &b.spelling)));
+    readings.dedup_by(|a, b| a.spelling == b.spelling);
+    readings.truncate(BEAM_WIDTH);
+}
+
+/// Returns the words of the lexicon with their letters, built on the first call.
+fn stems() -> &'static [(Entry, Vec<char>)] {
+    static STEMS: OnceLock<Vec<(Entry, Vec<char>)>> = OnceLock::new();
+    STEMS.get_or_init(|| {
+        lexicon()
+            .iter()
+            .map(|entry| (entry.clone(), entry.text.chars().collect()))
+            .collect()
+    })
+}
+
+/// Reads a whitespace-free chunk, allowing every word of the lexicon to be misspelled within its edit limit.
+fn correct_chunk(
+    chunk: &[char],
+    readings: Vec<Reading>,
+    lexicon: &[(Entry, Vec<char>)],
+) -> Vec<Reading> {
+    let mut beams = vec![Vec::new(); chunk.len() + 1];
+    beams[0] = readings;
+
+    for at in 0..chunk.len() {
+        let mut beam = std::mem::take(&mut beams[at]);
+        prune(&mut beam);
+        if beam.is_empty() {
+            continue;
+        }
+
+        for (entry, stem) in lexicon {
+            let limit = max_edits(stem.len() + entry.ordinal as usize);
+            let shortest = (stem.len() + entry.ordinal as usize)
+                .saturating_sub(limit)
+                .max(1);
+            let longest = stem.len() + entry.ordinal as usize + limit;
+
+            for length in shortest..=longest.min(chunk.len() - at) {
+                let mut part = &chunk[at..at + length];
+                let mut text = entry.text.clone();
+                let mut ending = None;
+                if entry.ordinal {
+                    let Some((last, rest)) = part.split_last() else {
+                        continue;
+                    };
+                    if !ORDINAL_ENDINGS.contains(last) {
+                        continue;
+                    }
+                    part = rest;
+                    text.push(*last);
+                    ending = Some(*last);
+                }
+
+                let edits = edit_distance(part, stem);
+                if edits > limit {
+                    continue;
+                }
+
+                let token = Token {
+                    kind: entry.kind,
+                    start: at,
+                    end: at + length,
+                    elided: entry.elided,
+                    ending,
+                };
+                for reading in &beam {
+                    let mut grammar = reading.grammar.clone();
+                    if grammar.push(&token, &text).is_err() {
+                        continue;
+                    }
+
+                    beams[at + length].push(Reading {
+                        grammar,
+                        edits: reading.edits + edits,
+                        words: reading.words + 1,
+                        spelling: format!("{}{}", reading.spelling, text),
+                    });
+                }
+            }
+        }
+    }
+
+    beams.pop().unwrap_or_default()
+}
+
+/// Converts a misspelled Italian word representation to a number, returning the corrected spelling
+///
+/// # Arguments
+///
+/// * `word` - The Italian word to convert, cardinal or ordinal.
+/// * `min_confidence` - The lowest confidence, from 0 to 1, of the returned correction.
+///
+/// Every word of the lexicon ("quattro", "cento", "mille", …) can be misspelled by up to a third of its letters,
+/// counting insertions, deletions, substitutions and swapped letters, as long as the result is a valid number.
+/// The reading with the fewest edits is returned, and its confidence is 1 minus the edits over the length of the
+/// corrected spelling.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::italian_fuzzy_converter;
+///
+/// let result = italian_fuzzy_converter("quatrocento", 0.5).unwrap();
+/// assert_eq!("quattrocento", result.spelling());
+/// assert_eq!(400, result.value());
+///
+/// let result = italian_fuzzy_converter("diciasette", 0.5).unwrap();
+/// assert_eq!(17, result.value());
+///
+/// let result = italian_fuzzy_converter("rnille", 0.5).unwrap();
+/// assert_eq!(("mille", 1_000), (result.spelling(), result.value()));
+///
+/// let result = italian_fuzzy_converter("rnille", 0.9);
+/// assert_eq!(Err("invalid word number"), result);
+/// ```
+pub fn italian_fuzzy_converter(
+    word: &str,
+    min_confidence: f32,
+) -> Result<ItalianCorrection, &'static str> {
+    let text = normalize(word).text;
+    if let Ok(number) = parse(word) {
+        return Ok(ItalianCorrection {
+            spelling: text.split_whitespace().collect::<Vec<_>>().join(" "),
+            value: number.value,
+            confidence: 1.0,
+        });
+    }
+
+    let mut readings = vec![Reading {
+        grammar: Grammar::new(),
+        edits: 0,
+        words: 0,
+        spelling: String::new(),
+    }];
+
+    for chunk in text.split_whitespace() {
+        for reading in &mut readings {
+            if !reading.spelling.is_empty() {
+                reading.spelling.push(' ');
+            }
+        }
+
+        if let Some(token) = connector(chunk) {
+            readings.retain_mut(|reading| {
+                reading.spelling.push_str(chunk);
+                reading.grammar.push(&token, chunk).is_ok()
+            });
+            continue;
+        }
+
+        readings = correct_chunk(&chunk.chars().collect::<Vec<_>>(), readings, stems());
+    }
+
+    readings.retain(|reading| reading.grammar.finish().is_ok());
+    prune(&mut readings);
+
+    readings
+        .into_iter()
+        .find_map(|reading| {
+            let number = parse(&reading.spelling).ok()?;
+            let length = reading.spelling.chars().count().max(1);
+            let confidence = 1.0 - reading.edits as f32 / length as f32;
+
+            (confidence >= min_confidence).then_some(ItalianCorrection {
+                spelling: reading.spelling,
+                value: number.value,
+                confidence,
+            })
+        })
+        .ok_or("invalid word number")
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, italian_fuzzy_converter};
+
+    fn corrected(word: &str) -> Option<(String, i64)> {
+        italian_fuzzy_converter(word, 0.5)
+            .ok()
+            .map(|correction| (correction.spelling().to_string(), correction.value()))
+    }
+
+    mod distance {
+        use super::*;
+
+        fn distance(a: &str, b: &str) -> usize {
+            edit_distance(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        }
+
+        #[test]
+        fn test_edit_distance() {
+            assert_eq!(distance("mille", "mille"), 0);
+            assert_eq!(distance("rnille", "mille"), 2);
+            assert_eq!(distance("setet", "sette"), 1);
+            assert_eq!(distance("", "tre"), 3);
+        }
+    }
+
+    mod corrections {
+        use super::*;
+
+        #[test]
+        fn test_misspellings() {
+            assert_eq!(corrected("rnille"), Some((String::from("mille"), 1_000)));
+            assert_eq!(
+                corrected("quatrocento"),
+                Some((String::from("quattrocento"), 400))
+            );
+            assert_eq!(
+                corrected("diciasette"),
+                Some((String::from("diciassette"), 17))
+            );
+            assert_eq!(
+                corrected("settantasete"),
+                Some((String::from("settantasette"), 77))
+            );
+        }
+
+        #[test]
+        fn test_spaces() {
+            assert_eq!(
+                corrected("tre milini e ventuno"),
+                Some((String::from("tre milioni e ventuno"), 3_000_021))
+            );
+        }
+
+        #[test]
+        fn test_ordinal() {
+            assert_eq!(corrected("ventesmo"), Some((String::from("ventesimo"), 20)));
+        }
+
+        #[test]
+        fn test_exact() {
+            let correction = italian_fuzzy_converter("Ventitré", 1.0).unwrap();
+            assert_eq!(correction.spelling(), "ventitré");
+            assert_eq!(correction.value(), 23);
+            assert_eq!(correction.confidence(), 1.0);
+        }
+
+        #[test]
+        fn test_confidence() {
+            let correction = italian_fuzzy_converter("quatrocento", 0.0).unwrap();
+            assert_eq!(correction.confidence(), 1.0 - 1.0 / 12.0);
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_threshold() {
+            assert_eq!(
+                italian_fuzzy_converter("rnille", 0.9),
+                Err("invalid word number")
+            );
+        }
+
+        #[test]
+        fn test_unreadable() {
+            assert_eq!(corrected("blabla"), None);
+            assert_eq!(corrected(""), None);
+        }
+    }
+}
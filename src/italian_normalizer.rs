@@ -0,0 +1,281 @@
+const COMBINING_GRAVE: char = '\u{300}';
+const COMBINING_ACUTE: char = '\u{301}';
+const APOSTROPHES: [char; 4] = ['\'', '’', '`', '´'];
+const HYPHENS: [char; 4] = ['-', '‐', '‑', '–'];
+
+/// A change made by `italian_normalizer` to read an Italian number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItalianNormalization {
+    /// A vowel followed by a combining accent (NFD) was composed into a single character (NFC).
+    Unicode,
+    /// Uppercase letters were lowercased.
+    Case,
+    /// An apostrophe after a vowel was read as an accent, e.g. "tre'" as "tré".
+    Apostrophe,
+    /// A grave accent on "e" was read as an acute accent, e.g. "trè" as "tré".
+    Accent,
+    /// A hyphen between letters was removed, e.g. "venti-tre".
+    Hyphen,
+}
+
+fn accented(vowel: char, accent: char) -> Option<char> {
+    let accented = match (vowel, accent) {
+        ('a', COMBINING_GRAVE) => 'à',
+        ('a', COMBINING_ACUTE) => 'á',
+        ('e', COMBINING_GRAVE) => 'è',
+        ('e', COMBINING_ACUTE) => 'é',
+        ('i', COMBINING_GRAVE) => 'ì',
+        ('i', COMBINING_ACUTE) => 'í',
+        ('o', COMBINING_GRAVE) => 'ò',
+        ('o', COMBINING_ACUTE) => 'ó',
+        ('u', COMBINING_GRAVE) => 'ù',
+        ('u', COMBINING_ACUTE) => 'ú',
+        _ => return None,
+    };
+
+    Some(accented)
+}
+
+/// The normalized text, the position in the original text of every byte of it, and the changes made.
+pub(crate) struct Normalized {
+    pub(crate) text: String,
+    pub(crate) positions: Vec<usize>,
+    pub(crate) normalizations: Vec<ItalianNormalization>,
+}
+
+impl Normalized {
+    fn record(&mut self, normalization: ItalianNormalization) {
+        if !self.normalizations.contains(&normalization) {
+            self.normalizations.push(normalization);
+        }
+    }
+
+    fn push(&mut self, letter: char, position: usize) {
+        let letter = if letter == 'è' {
+            self.record(ItalianNormalization::Accent);
+            'é'
+        } else {
+            letter
+        };
+
+        self.text.push(letter);
+        self.positions.resize(self.text.len(), position);
+    }
+
+    /// Replaces the last letter, keeping its position in the original text.
+    fn replace_last(&mut self, letter: char) {
+        if self.text.pop().is_none() {
+            return;
+        }
+        let position = self.positions[self.text.len()];
+        self.positions.truncate(self.text.len());
+        self.push(letter, position);
+    }
+}
+
+pub(crate) fn normalize(word: &str) -> Normalized {
+    let mut normalized = Normalized {
+        text: String::with_capacity(word.len()),
+        positions: Vec::with_capacity(word.len() + 1),
+        normalizations: Vec::new(),
+    };
+    let mut letters = word.char_indices().peekable();
+
+    while let Some((position, letter)) = letters.next() {
+        let last = normalized.text.chars().next_back();
+        let next = letters.peek().map(|(_, next)| *next);
+
+        if matches!(letter, COMBINING_GRAVE | COMBINING_ACUTE) {
+            if let Some(composed) = last.and_then(|last| accented(last, letter)) {
+                normalized.record(ItalianNormalization::Unicode);
+                normalized.replace_last(composed);
+                continue;
+            }
+        }
+
+        if APOSTROPHES.contains(&letter) && !next.is_some_and(char::is_alphabetic) {
+            let accent = if last == Some('e') {
+                COMBINING_ACUTE
+            } else {
+                COMBINING_GRAVE
+            };
+            if let Some(composed) = last.and_then(|last| accented(last, accent)) {
+                normalized.record(ItalianNormalization::Apostrophe);
+                normalized.replace_last(composed);
+                continue;
+            }
+        }
+
+        if HYPHENS.contains(&letter)
+            && last.is_some_and(char::is_alphabetic)
+            && next.is_some_and(char::is_alphabetic)
+        {
+            normalized.record(ItalianNormalization::Hyphen);
+            continue;
+        }
+
+        if letter.is_uppercase() {
+            normalized.record(ItalianNormalization::Case);
+            for lower in letter.to_lowercase() {
+                normalized.push(lower, position);
+            }
+            continue;
+        }
+
+        normalized.push(letter, position);
+    }
+    normalized.positions.push(word.len());
+
+    normalized
+}
+
+/// Normalizes the way an Italian number is written, returning the normalized word and the changes made
+///
+/// # Arguments
+///
+/// * `word` - The Italian word to normalize.
+///
+/// The word is lowercased, NFD accents are composed, an apostrophe after a final vowel is read as an accent,
+/// "è" is read as "é" and hyphens between letters are removed. `italian_parser` and `italian_converter` apply the
+/// same normalization before reading a number.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{italian_normalizer, ItalianNormalization};
+///
+/// let result = italian_normalizer("Venti-tre'");
+/// assert_eq!(
+///     (
+///         String::from("ventitré"),
+///         vec![
+///             ItalianNormalization::Case,
+///             ItalianNormalization::Hyphen,
+///             ItalianNormalization::Apostrophe
+///         ]
+///     ),
+///     result
+/// );
+///
+/// let result = italian_normalizer("ventitrè");
+/// assert_eq!(
+///     (String::from("ventitré"), vec![ItalianNormalization::Accent]),
+///     result
+/// );
+/// ```
+pub fn italian_normalizer(word: &str) -> (String, Vec<ItalianNormalization>) {
+    let normalized = normalize(word);
+
+    (normalized.text, normalized.normalizations)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{italian_normalizer, normalize, ItalianNormalization};
+    use crate::italian_parser;
+
+    mod normalizations {
+        use super::*;
+
+        #[test]
+        fn test_unicode() {
+            assert_eq!(
+                italian_normalizer("tre\u{301}"),
+                (String::from("tré"), vec![ItalianNormalization::Unicode])
+            );
+            assert_eq!(
+                italian_normalizer("tre\u{300}"),
+                (
+                    String::from("tré"),
+                    vec![ItalianNormalization::Unicode, ItalianNormalization::Accent]
+                )
+            );
+        }
+
+        #[test]
+        fn test_case() {
+            assert_eq!(
+                italian_normalizer("VENTITRÉ"),
+                (String::from("ventitré"), vec![ItalianNormalization::Case])
+            );
+        }
+
+        #[test]
+        fn test_apostrophe() {
+            assert_eq!(
+                italian_normalizer("ventitre’"),
+                (
+                    String::from("ventitré"),
+                    vec![ItalianNormalization::Apostrophe]
+                )
+            );
+            assert_eq!(
+                italian_normalizer("ventitre' milioni"),
+                (
+                    String::from("ventitré milioni"),
+                    vec![ItalianNormalization::Apostrophe]
+                )
+            );
+        }
+
+        #[test]
+        fn test_hyphen() {
+            assert_eq!(
+                italian_normalizer("trenta-tre"),
+                (
+                    String::from("trentatre"),
+                    vec![ItalianNormalization::Hyphen]
+                )
+            );
+            assert_eq!(
+                italian_normalizer("-tre"),
+                (String::from("-tre"), Vec::new())
+            );
+        }
+
+        #[test]
+        fn test_unchanged() {
+            assert_eq!(
+                italian_normalizer("ventitré"),
+                (String::from("ventitré"), Vec::new())
+            );
+        }
+
+        #[test]
+        fn test_positions() {
+            let normalized = normalize("TRE\u{301}-bla");
+            assert_eq!(normalized.text, "trébla");
+            assert_eq!(normalized.positions, vec![0, 1, 2, 2, 6, 7, 8, 9]);
+        }
+    }
+
+    mod parser {
+        use super::*;
+
+        #[test]
+        fn test_variants() {
+            let words = [
+                "ventitre",
+                "ventitre'",
+                "ventitrè",
+                "ventitré",
+                "VENTITRÉ",
+                "ventitre\u{301}",
+                "venti-tre",
+            ];
+
+            for word in words {
+                assert_eq!(italian_parser(word), Ok(23), "{}", word);
+            }
+        }
+
+        #[test]
+        fn test_error_position() {
+            let error = italian_parser("tre\u{301}-bla").unwrap_err();
+            assert_eq!((error.start(), error.end()), (6, 9));
+            assert_eq!(error.token(), "bla");
+        }
+    }
+}
@@ -0,0 +1,645 @@
+use crate::italian_normalizer::normalize;
+use crate::italian_parser::{parse, ORDINAL_ENDINGS};
+use crate::italian_strict_converter::canonical_spelling;
+use crate::ordinal_indicator_converter::gender_and_number;
+use crate::{
+    cardinal_converter, ordinal_converter, ordinal_indicator_converter, ordinal_indicator_parser,
+    ItalianError, Options, OrdinalIndicator,
+};
+
+const HALF: [&str; 2] = ["mezzo", "mezzi"];
+/// The most words of a number: "meno nove cento novanta nove miliardi e ..." spelled word by word has 22.
+const MAX_WORDS: usize = 32;
+const MULTIPLICATIVES: [(&str, i64); 10] = [
+    ("doppi", 2),
+    ("tripl", 3),
+    ("quadrupl", 4),
+    ("quintupl", 5),
+    ("sestupl", 6),
+    ("settupl", 7),
+    ("ottupl", 8),
+    ("nonupl", 9),
+    ("decupl", 10),
+    ("centupl", 100),
+];
+
+/// The kind of number read by `italian_number_parser`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItalianKind {
+    /// A cardinal number in words, e.g. "ventitré".
+    Cardinal,
+    /// An ordinal number in words, e.g. "ventitreesima".
+    Ordinal,
+    /// An ordinal number with digits and an indicator, e.g. "23ª".
+    ShortOrdinal,
+    /// A fraction, with a cardinal numerator and an ordinal denominator, e.g. "tre quarti".
+    Fraction,
+    /// A multiplicative number, e.g. "triplo".
+    Multiplicative,
+}
+
+/// A number read by `italian_number_parser`, with what its spelling tells about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItalianNumber {
+    value: i64,
+    denominator: i64,
+    kind: ItalianKind,
+    options: Option<Options>,
+    canonical: bool,
+    start: usize,
+    end: usize,
+}
+
+impl ItalianNumber {
+    /// Returns the value of the number, or the numerator of a fraction.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Returns the denominator of a fraction, or 1 for the other kinds.
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Returns the kind of the number.
+    pub fn kind(&self) -> ItalianKind {
+        self.kind
+    }
+
+    /// Returns the gender and plurality of an ordinal, fraction or multiplicative, or the feminine of a cardinal
+    /// ending in "una".
+    pub fn options(&self) -> Option<Options> {
+        self.options
+    }
+
+    /// Returns `true` if the number is written as the converters of this crate would write it.
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Returns the byte position where the number starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte position where the number ends.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+/// Returns the number spanning the trimmed `word`, with the given value, kind, options and canonical flag.
+fn number(
+    word: &str,
+    value: i64,
+    kind: ItalianKind,
+    options: Option<Options>,
+    canonical: bool,
+) -> ItalianNumber {
+    let start = word.len() - word.trim_start().len();
+
+    ItalianNumber {
+        value,
+        denominator: 1,
+        kind,
+        options,
+        canonical,
+        start,
+        end: start + word.trim().len(),
+    }
+}
+
+fn words_parser(word: &str) -> Result<ItalianNumber, ItalianError> {
+    let parsed = parse(word)?;
+
+    let (kind, options) = match parsed.ending {
+        Some(ending) => (ItalianKind::Ordinal, Some(gender_and_number(ending))),
+        None if parsed.is_feminine => (ItalianKind::Cardinal, Some(Options::new(true, false))),
+        None => (ItalianKind::Cardinal, None),
+    };
+    let canonical = canonical_spelling(parsed.value, parsed.ending)
+        .is_ok_and(|expected| expected == word.trim().to_lowercase());
+
+    Ok(number(word, parsed.value, kind, options, canonical))
+}
+
+/// Reads a cardinal numerator followed by an ordinal denominator, plural when the numerator is not one:
+/// "un terzo", "tre quarti", "un mezzo".
+fn fraction_parser(word: &str) -> Option<ItalianNumber> {
+    let (numerator, denominator) = word.trim().rsplit_once(char::is_whitespace)?;
+
+    let numerator = match normalize(numerator).text.trim() {
+        "un" => 1,
+        _ => {
+            let parsed = parse(numerator).ok()?;
+            if parsed.ending.is_some() || parsed.is_feminine || parsed.value < 1 {
+                return None;
+            }
+            parsed.value
+        }
+    };
+
+    let normalized = normalize(denominator).text;
+    let (denominator, plural) = match HALF.iter().position(|half| *half == normalized) {
+        Some(index) => (2, index == 1),
+        None => {
+            let parsed = parse(denominator).ok()?;
+            match parsed.ending {
+                Some(ending @ ('o' | 'i')) if parsed.value > 2 => (parsed.value, ending == 'i'),
+                _ => return None,
+            }
+        }
+    };
+    if plural != (numerator > 1) {
+        return None;
+    }
+
+    let options = Options::new(false, plural);
+    let expected_numerator = match numerator {
+        1 => String::from("un"),
+        _ => cardinal_converter(numerator as f64, false).ok()?,
+    };
+    let expected_denominator = match denominator {
+        2 => HALF[plural as usize].to_string(),
+        _ => ordinal_converter(denominator as u64, Some(options)).ok()?,
+    };
+    let expected = format!("{} {}", expected_numerator, expected_denominator);
+    let canonical = expected == word.trim().to_lowercase();
+
+    Some(ItalianNumber {
+        denominator,
+        ..number(
+            word,
+            numerator,
+            ItalianKind::Fraction,
+            Some(options),
+            canonical,
+        )
+    })
+}
+
+/// Returns a multiplicative with its ending, where a stem ending in "i" takes no other "i": "doppi", "tripli".
+fn multiplicative_spelling(stem: &str, ending: char) -> String {
+    match (stem.strip_suffix('i'), ending) {
+        (Some(_), 'i') => stem.to_string(),
+        _ => format!("{}{}", stem, ending),
+    }
+}
+
+/// Reads a multiplicative number, like "doppio", "tripla" or "quadrupli".
+fn multiplicative_parser(word: &str) -> Option<ItalianNumber> {
+    let normalized = normalize(word).text;
+    let (spelling, value, ending) = MULTIPLICATIVES.iter().find_map(|(stem, value)| {
+        ORDINAL_ENDINGS.iter().find_map(|&ending| {
+            let spelling = multiplicative_spelling(stem, ending);
+            (spelling == normalized.trim()).then_some((spelling, *value, ending))
+        })
+    })?;
+
+    Some(number(
+        word,
+        value,
+        ItalianKind::Multiplicative,
+        Some(gender_and_number(ending)),
+        spelling == word.trim().to_lowercase(),
+    ))
+}
+
+fn short_ordinal_parser(word: &str) -> Option<ItalianNumber> {
+    let text = word.trim();
+    let (value, options) = ordinal_indicator_parser(text).ok()?;
+    let indicators = [
+        OrdinalIndicator::Ordinal,
+        OrdinalIndicator::Degree,
+        OrdinalIndicator::Letter,
+        OrdinalIndicator::Html,
+    ];
+    let canonical = indicators
+        .iter()
+        .any(|indicator| ordinal_indicator_converter(value, Some(options), *indicator) == text);
+
+    Some(number(
+        word,
+        i64::try_from(value).ok()?,
+        ItalianKind::ShortOrdinal,
+        Some(options),
+        canonical,
+    ))
+}
+
+fn number_parser(word: &str) -> Result<ItalianNumber, ItalianError> {
+    match words_parser(word) {
+        // An ordinal with spaces, like "sette centesimi", is read as a fraction when it can be one.
+        Ok(number)
+            if number.kind == ItalianKind::Ordinal && word.trim().contains(char::is_whitespace) =>
+        {
+            Ok(fraction_parser(word).unwrap_or(number))
+        }
+        Ok(number) => Ok(number),
+        Err(error) => fraction_parser(word)
+            .or_else(|| multiplicative_parser(word))
+            .or_else(|| short_ordinal_parser(word))
+            .ok_or(error),
+    }
+}
+
+/// Converts an Italian number to its value, with its kind, gender, plurality, whether it is canonical and the span
+/// it was read from
+///
+/// # Arguments
+///
+/// * `word` - The text to read the number from: a cardinal or ordinal in words, as read by `italian_parser`, a
+///   fraction ("un terzo", "tre quarti"), a multiplicative ("doppio", "triplo") or a short ordinal, as read by
+///   `ordinal_indicator_parser`. Spaces around the number are ignored.
+///
+/// When the whole text is not a number, the longest run of leading words that is one is read, among the first 32
+/// words, so "tre quarti di litro" reads "tre quarti", and the span ends where the number does. For a fraction, the value is the numerator
+/// and `denominator` returns the denominator.
+///
+/// A number is canonical when it is written exactly as `cardinal_converter`, `ordinal_converter` or
+/// `ordinal_indicator_converter` would write it, ignoring case; fractions are canonical with "un" for a numerator of
+/// one and "mezzo" for halves. On failure, the error is the one of `italian_parser`.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{italian_number_parser, ItalianKind, Options};
+///
+/// let result = italian_number_parser("quattrocentotredicesime").unwrap();
+/// assert_eq!(413, result.value());
+/// assert_eq!(ItalianKind::Ordinal, result.kind());
+/// assert_eq!(Some(Options::new(true, true)), result.options());
+/// assert!(result.is_canonical());
+///
+/// let result = italian_number_parser(" ventiuno ").unwrap();
+/// assert_eq!((ItalianKind::Cardinal, None), (result.kind(), result.options()));
+/// assert!(!result.is_canonical());
+/// assert_eq!((1, 9), (result.start(), result.end()));
+///
+/// let result = italian_number_parser("2ª").unwrap();
+/// assert_eq!(ItalianKind::ShortOrdinal, result.kind());
+/// assert_eq!(Some(Options::new(true, false)), result.options());
+///
+/// let result = italian_number_parser("tre quarti di litro").unwrap();
+/// assert_eq!(ItalianKind::Fraction, result.kind());
+/// assert_eq!((3, 4), (result.value(), result.denominator()));
+/// assert_eq!((0, 10), (result.start(), result.end()));
+///
+/// let result = italian_number_parser("tripla").unwrap();
+/// assert_eq!((3, ItalianKind::Multiplicative), (result.value(), result.kind()));
+/// ```
+pub fn italian_number_parser(word: &str) -> Result<ItalianNumber, ItalianError> {
+    let error = match number_parser(word) {
+        Ok(number) => return Ok(number),
+        Err(error) => error,
+    };
+
+    let start = word.len() - word.trim_start().len();
+    let text = word.trim();
+    let word_ends = text
+        .char_indices()
+        .zip(text.chars().skip(1))
+        .filter(|((_, letter), next)| !letter.is_whitespace() && next.is_whitespace())
+        .map(|((position, letter), _)| start + position + letter.len_utf8())
+        .take(MAX_WORDS)
+        .collect::<Vec<_>>();
+
+    word_ends
+        .into_iter()
+        .rev()
+        .find_map(|end| number_parser(&word[..end]).ok())
+        .ok_or(error)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{italian_number_parser, ItalianKind};
+    use crate::Options;
+
+    fn summary(word: &str) -> (i64, ItalianKind, Option<Options>, bool) {
+        let number = italian_number_parser(word).unwrap();
+        (
+            number.value(),
+            number.kind(),
+            number.options(),
+            number.is_canonical(),
+        )
+    }
+
+    mod kinds {
+        use super::*;
+
+        #[test]
+        fn test_cardinal() {
+            assert_eq!(summary("ventitré"), (23, ItalianKind::Cardinal, None, true));
+            assert_eq!(
+                summary("mille e una"),
+                (
+                    1_001,
+                    ItalianKind::Cardinal,
+                    Some(Options::new(true, false)),
+                    false
+                )
+            );
+        }
+
+        #[test]
+        fn test_ordinal() {
+            assert_eq!(
+                summary("primo"),
+                (
+                    1,
+                    ItalianKind::Ordinal,
+                    Some(Options::new(false, false)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("ventesimi"),
+                (
+                    20,
+                    ItalianKind::Ordinal,
+                    Some(Options::new(false, true)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("quattrocentotredicesime"),
+                (
+                    413,
+                    ItalianKind::Ordinal,
+                    Some(Options::new(true, true)),
+                    true
+                )
+            );
+        }
+
+        #[test]
+        fn test_short_ordinal() {
+            assert_eq!(
+                summary("1ª"),
+                (
+                    1,
+                    ItalianKind::ShortOrdinal,
+                    Some(Options::new(true, false)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("10mo"),
+                (
+                    10,
+                    ItalianKind::ShortOrdinal,
+                    Some(Options::new(false, false)),
+                    false
+                )
+            );
+            assert_eq!(
+                summary("XX°"),
+                (
+                    20,
+                    ItalianKind::ShortOrdinal,
+                    Some(Options::new(false, false)),
+                    false
+                )
+            );
+        }
+    }
+
+    mod fractions {
+        use super::*;
+
+        fn fraction(word: &str) -> (i64, i64, Option<Options>, bool) {
+            let number = italian_number_parser(word).unwrap();
+            assert_eq!(number.kind(), ItalianKind::Fraction, "{}", word);
+            (
+                number.value(),
+                number.denominator(),
+                number.options(),
+                number.is_canonical(),
+            )
+        }
+
+        #[test]
+        fn test_fractions() {
+            assert_eq!(
+                fraction("un terzo"),
+                (1, 3, Some(Options::new(false, false)), true)
+            );
+            assert_eq!(
+                fraction("tre quarti"),
+                (3, 4, Some(Options::new(false, true)), true)
+            );
+            assert_eq!(
+                fraction("sette centesimi"),
+                (7, 100, Some(Options::new(false, true)), true)
+            );
+            assert_eq!(
+                fraction("ventuno millesimi"),
+                (21, 1_000, Some(Options::new(false, true)), true)
+            );
+        }
+
+        #[test]
+        fn test_halves() {
+            assert_eq!(
+                fraction("un mezzo"),
+                (1, 2, Some(Options::new(false, false)), true)
+            );
+            assert_eq!(
+                fraction("cinque mezzi"),
+                (5, 2, Some(Options::new(false, true)), true)
+            );
+        }
+
+        #[test]
+        fn test_non_canonical() {
+            assert_eq!(
+                fraction("uno terzo"),
+                (1, 3, Some(Options::new(false, false)), false)
+            );
+            assert_eq!(
+                fraction("Tre Quarti"),
+                (3, 4, Some(Options::new(false, true)), true)
+            );
+        }
+
+        #[test]
+        fn test_not_fractions() {
+            for (word, kind) in [
+                ("tre quarto", ItalianKind::Cardinal),
+                ("un terzi", ItalianKind::Cardinal),
+                ("due secondi", ItalianKind::Cardinal),
+                ("terzo quarto", ItalianKind::Ordinal),
+            ] {
+                let number = italian_number_parser(word).unwrap();
+                assert_eq!(
+                    (number.kind(), number.denominator(), number.end()),
+                    (kind, 1, word.find(' ').unwrap()),
+                    "{}",
+                    word
+                );
+            }
+        }
+
+        #[test]
+        fn test_ordinal_with_spaces() {
+            assert_eq!(fraction("sette centesimi").1, 100);
+            assert_eq!(
+                summary("sette centesimo"),
+                (
+                    700,
+                    ItalianKind::Ordinal,
+                    Some(Options::new(false, false)),
+                    false
+                )
+            );
+        }
+    }
+
+    mod multiplicatives {
+        use super::*;
+
+        #[test]
+        fn test_multiplicatives() {
+            assert_eq!(
+                summary("doppio"),
+                (
+                    2,
+                    ItalianKind::Multiplicative,
+                    Some(Options::new(false, false)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("tripla"),
+                (
+                    3,
+                    ItalianKind::Multiplicative,
+                    Some(Options::new(true, false)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("doppi"),
+                (
+                    2,
+                    ItalianKind::Multiplicative,
+                    Some(Options::new(false, true)),
+                    true
+                )
+            );
+            assert_eq!(
+                summary("Centuple"),
+                (
+                    100,
+                    ItalianKind::Multiplicative,
+                    Some(Options::new(true, true)),
+                    true
+                )
+            );
+        }
+
+        #[test]
+        fn test_not_multiplicatives() {
+            assert!(italian_number_parser("doppiu").is_err());
+            assert!(italian_number_parser("doppii").is_err());
+            assert!(italian_number_parser("duplo").is_err());
+        }
+    }
+
+    mod canonical {
+        use super::*;
+
+        #[test]
+        fn test_non_canonical() {
+            assert!(!summary("ventiuno").3);
+            assert!(!summary("VENTI-TRE").3);
+            assert!(!summary("centoottesimo").3);
+        }
+
+        #[test]
+        fn test_case() {
+            assert!(summary("Ventitré").3);
+        }
+    }
+
+    mod spans {
+        use super::*;
+
+        #[test]
+        fn test_span() {
+            let number = italian_number_parser("  tre milioni ").unwrap();
+            assert_eq!((number.start(), number.end()), (2, 13));
+
+            let number = italian_number_parser(" 2ª").unwrap();
+            assert_eq!((number.start(), number.end()), (1, 4));
+        }
+
+        #[test]
+        fn test_consumed_span() {
+            let number = italian_number_parser(" tre quarti di litro").unwrap();
+            assert_eq!((number.value(), number.denominator()), (3, 4));
+            assert_eq!((number.start(), number.end()), (1, 11));
+
+            let number = italian_number_parser("ventitré gatti neri").unwrap();
+            assert_eq!(number.value(), 23);
+            assert_eq!((number.start(), number.end()), (0, 9));
+
+            let number = italian_number_parser("2ª  classe").unwrap();
+            assert_eq!((number.start(), number.end()), (0, 3));
+
+            let number = italian_number_parser("il doppio").unwrap_err();
+            assert_eq!(number.token(), "il");
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid() {
+            let error = italian_number_parser("ventottobla").unwrap_err();
+            assert_eq!((error.start(), error.end()), (8, 11));
+        }
+
+        #[test]
+        fn test_words_starting_with_roman_letters() {
+            assert!(italian_number_parser("Io").is_err());
+            assert!(italian_number_parser("Mi").is_err());
+        }
+
+        #[test]
+        fn test_invalid_span_after_spaces() {
+            let error = italian_number_parser("  ventottobla").unwrap_err();
+            assert_eq!((error.start(), error.end()), (10, 13));
+            assert_eq!(error.token(), "bla");
+        }
+
+        #[test]
+        fn test_long_text() {
+            let word = "tre ".repeat(100_000);
+            let number = italian_number_parser(&word).unwrap();
+            assert_eq!((number.value(), number.end()), (3, 3));
+
+            let longest =
+                "meno nove cento novanta nove miliardi e nove cento novanta nove milioni e nove \
+                cento novanta nove mila nove cento novanta nove";
+            let word = format!("{} gatti {}", longest, word);
+            let number = italian_number_parser(&word).unwrap();
+            assert_eq!(
+                (number.value(), number.end()),
+                (-999_999_999_999, longest.len())
+            );
+        }
+
+        #[test]
+        fn test_negative_ordinal() {
+            let error = italian_number_parser("meno primo").unwrap_err();
+            assert_eq!((error.start(), error.end()), (5, 10));
+            assert_eq!(error.expected(), "a cardinal number after \"meno\"");
+        }
+    }
+}
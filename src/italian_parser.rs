@@ -0,0 +1,926 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::italian_normalizer::{normalize, Normalized};
+use crate::{HUNDRED, TENS, THOUSANDS, ZERO_NINETEEN, ZERO_TEN_ORDINALS};
+
+const MINUS: &str = "meno";
+pub(crate) const CONJUNCTION: &str = "e";
+const OF: &str = "di";
+const MILLION: [&str; 2] = ["milione", "milioni"];
+const BILLION: [&str; 2] = ["miliardo", "miliardi"];
+const THOUSANDS_NOUN: &str = "migliaia";
+const HALF: [&str; 2] = ["mezzo", "mezza"];
+const DOZEN: [&str; 2] = ["dozzina", "dozzine"];
+const ORDINAL_SUFFIX: &str = "esim";
+pub(crate) const ORDINAL_ENDINGS: [char; 4] = ['o', 'a', 'i', 'e'];
+
+/// An error found by `italian_parser`, with the position of the word that could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItalianError {
+    start: usize,
+    end: usize,
+    token: String,
+    expected: &'static str,
+}
+
+impl ItalianError {
+    /// Returns the byte position where the invalid word starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte position where the invalid word ends.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the invalid word, empty if the number ended too early.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns a description of what was expected in place of the invalid word.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+}
+
+impl fmt::Display for ItalianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.token.is_empty() {
+            return write!(
+                f,
+                "unexpected end at {}, expected {}",
+                self.start, self.expected
+            );
+        }
+
+        write!(
+            f,
+            "unexpected \"{}\" at {}..{}, expected {}",
+            self.token, self.start, self.end, self.expected
+        )
+    }
+}
+
+/// The grammatical number of a multiplier word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Form {
+    /// "mille", "milione", "miliardo".
+    Singular,
+    /// "mila", "milioni", "miliardi".
+    Plural,
+    /// Elided ("miliard") and ordinal ("millesimo") multipliers, which fit any count.
+    Any,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Minus,
+    Conjunction,
+    Of,
+    Zero,
+    Unit(u8),
+    Teen(u8),
+    Ten(u8),
+    Hundred,
+    Multiplier(u32, Form),
+    IrregularOrdinal(u8),
+    Half,
+    Dozen(Form),
+}
+
+/// A word of the lexicon. Ordinal words are stored without their final vowel.
+#[derive(Clone)]
+pub(crate) struct Entry {
+    pub(crate) text: String,
+    pub(crate) kind: Kind,
+    pub(crate) elided: bool,
+    pub(crate) ordinal: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Token {
+    pub(crate) kind: Kind,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) elided: bool,
+    pub(crate) ending: Option<char>,
+}
+
+/// The result of the grammar, used by the converters built on top of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParsedNumber {
+    pub(crate) value: i64,
+    pub(crate) ending: Option<char>,
+    pub(crate) is_feminine: bool,
+}
+
+fn entry(text: &str, kind: Kind, elided: bool, ordinal: bool) -> Entry {
+    Entry {
+        text: text.to_string(),
+        kind,
+        elided,
+        ordinal,
+    }
+}
+
+fn ordinal_stem(word: &str) -> String {
+    match word {
+        "tre" | "sei" => format!("{}{}", word, ORDINAL_SUFFIX),
+        "dieci" => String::from("decim"),
+        _ => format!("{}{}", &word[..word.len() - 1], ORDINAL_SUFFIX),
+    }
+}
+
+fn build_lexicon() -> Vec<Entry> {
+    let mut lexicon = vec![
+        entry(MINUS, Kind::Minus, false, false),
+        entry(ZERO_NINETEEN[0], Kind::Zero, false, false),
+        entry("un", Kind::Unit(1), false, false),
+        entry("una", Kind::Unit(1), false, false),
+        entry("tré", Kind::Unit(3), false, false),
+        entry(HUNDRED, Kind::Hundred, false, false),
+        entry(&HUNDRED[..HUNDRED.len() - 1], Kind::Hundred, true, false),
+        entry(&ordinal_stem(HUNDRED), Kind::Hundred, false, true),
+        entry(
+            THOUSANDS[0],
+            Kind::Multiplier(3, Form::Singular),
+            false,
+            false,
+        ),
+        entry(
+            THOUSANDS[1],
+            Kind::Multiplier(3, Form::Plural),
+            false,
+            false,
+        ),
+        entry(
+            &ordinal_stem(THOUSANDS[0]),
+            Kind::Multiplier(3, Form::Any),
+            false,
+            true,
+        ),
+    ];
+
+    lexicon.push(entry(
+        THOUSANDS_NOUN,
+        Kind::Multiplier(3, Form::Plural),
+        false,
+        false,
+    ));
+    for word in HALF {
+        lexicon.push(entry(word, Kind::Half, false, false));
+    }
+    lexicon.push(entry(DOZEN[0], Kind::Dozen(Form::Singular), false, false));
+    lexicon.push(entry(DOZEN[1], Kind::Dozen(Form::Plural), false, false));
+
+    for (exponent, words) in [(6, MILLION), (9, BILLION)] {
+        let singular = words[0];
+        lexicon.push(entry(
+            singular,
+            Kind::Multiplier(exponent, Form::Singular),
+            false,
+            false,
+        ));
+        lexicon.push(entry(
+            words[1],
+            Kind::Multiplier(exponent, Form::Plural),
+            false,
+            false,
+        ));
+        lexicon.push(entry(
+            &singular[..singular.len() - 1],
+            Kind::Multiplier(exponent, Form::Any),
+            true,
+            false,
+        ));
+        lexicon.push(entry(
+            &ordinal_stem(singular),
+            Kind::Multiplier(exponent, Form::Any),
+            false,
+            true,
+        ));
+    }
+
+    for (value, word) in ZERO_NINETEEN.iter().enumerate().skip(1) {
+        let kind = if value < 10 {
+            Kind::Unit(value as u8)
+        } else {
+            Kind::Teen(value as u8)
+        };
+        lexicon.push(entry(word, kind, false, false));
+        lexicon.push(entry(&ordinal_stem(word), kind, false, true));
+    }
+
+    for (index, word) in TENS.iter().enumerate() {
+        let kind = Kind::Ten((index as u8 + 2) * 10);
+        lexicon.push(entry(word, kind, false, false));
+        lexicon.push(entry(&word[..word.len() - 1], kind, true, false));
+        lexicon.push(entry(&ordinal_stem(word), kind, false, true));
+    }
+
+    for (value, word) in ZERO_TEN_ORDINALS.iter().enumerate().take(10) {
+        let stem = &word[..word.len() - 1];
+        lexicon.push(entry(
+            stem,
+            Kind::IrregularOrdinal(value as u8),
+            false,
+            true,
+        ));
+    }
+
+    lexicon
+}
+
+/// Returns the words of the lexicon, built on the first call.
+pub(crate) fn lexicon() -> &'static [Entry] {
+    static LEXICON: OnceLock<Vec<Entry>> = OnceLock::new();
+    LEXICON.get_or_init(build_lexicon)
+}
+
+/// Returns the words of the lexicon that start `chunk` at `at`, the longest first, with their length and ending.
+fn candidates<'a>(
+    chunk: &str,
+    at: usize,
+    lexicon: &'a [Entry],
+) -> Vec<(&'a Entry, usize, Option<char>)> {
+    let rest = &chunk[at..];
+    let mut candidates = lexicon
+        .iter()
+        .filter_map(|entry| {
+            let after = rest.strip_prefix(entry.text.as_str())?;
+            if !entry.ordinal {
+                return Some((entry, entry.text.len(), None));
+            }
+
+            let ending = after
+                .chars()
+                .next()
+                .filter(|c| ORDINAL_ENDINGS.contains(c))?;
+            Some((entry, entry.text.len() + 1, Some(ending)))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.1));
+
+    candidates
+}
+
+/// Splits a whitespace-free chunk into lexicon words, trying the longest words first.
+/// The search keeps its own stack and remembers the positions that cannot be read to the end, so it takes linear
+/// time and does not recurse. On failure, the error holds the position of the first character that could not be
+/// read and the words read before it.
+fn segment(chunk: &str, lexicon: &[Entry]) -> Result<Vec<Token>, (usize, Vec<Token>)> {
+    let mut dead_ends = vec![false; chunk.len()];
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut stack = vec![(0, candidates(chunk, 0, lexicon).into_iter())];
+    let mut furthest = 0;
+    let mut best = Vec::new();
+    let mut best_prefix = 0;
+
+    while let Some((at, words)) = stack.last_mut() {
+        let at = *at;
+        let Some((entry, length, ending)) = words.next() else {
+            dead_ends[at] = true;
+            stack.pop();
+            tokens.pop();
+            best_prefix = best_prefix.min(tokens.len());
+            continue;
+        };
+
+        let end = at + length;
+        if end < chunk.len() && dead_ends[end] {
+            continue;
+        }
+        tokens.push(Token {
+            kind: entry.kind,
+            start: at,
+            end,
+            elided: entry.elided,
+            ending,
+        });
+        if end == chunk.len() {
+            return Ok(tokens);
+        }
+
+        if end >= furthest {
+            furthest = end;
+            best.truncate(best_prefix);
+            best.extend_from_slice(&tokens[best_prefix..]);
+            best_prefix = tokens.len();
+        }
+        stack.push((end, candidates(chunk, end, lexicon).into_iter()));
+    }
+
+    Err((furthest, best))
+}
+
+/// The part of the current group of three digits read so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Empty,
+    Multiplicand(u8),
+    Hundreds(u16),
+    Ten(u16),
+    Closed(u16),
+}
+
+impl Phase {
+    fn value(&self) -> u16 {
+        match *self {
+            Phase::Empty => 0,
+            Phase::Multiplicand(unit) => unit as u16,
+            Phase::Hundreds(value) | Phase::Ten(value) | Phase::Closed(value) => value,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Grammar {
+    is_negative: bool,
+    total: i64,
+    phase: Phase,
+    exponent: u32,
+    has_number: bool,
+    after_conjunction: bool,
+    after_dozen: bool,
+    after_of: bool,
+    elided: Option<&'static str>,
+    ending: Option<char>,
+    is_finished: bool,
+    bare_multiplier: Option<Token>,
+}
+
+impl Grammar {
+    pub(crate) fn new() -> Grammar {
+        Grammar {
+            is_negative: false,
+            total: 0,
+            phase: Phase::Empty,
+            exponent: 12,
+            has_number: false,
+            after_conjunction: false,
+            after_dozen: false,
+            after_of: false,
+            elided: None,
+            ending: None,
+            is_finished: false,
+            bare_multiplier: None,
+        }
+    }
+
+    fn expected(&self) -> &'static str {
+        if self.is_finished {
+            return "the end of the number";
+        }
+        if let Some(expected) = self.elided {
+            return expected;
+        }
+        if self.after_of {
+            return "\"migliaia\", \"milioni\" or \"miliardi\" after \"di\"";
+        }
+        if !self.has_number || self.after_conjunction {
+            return "a number";
+        }
+
+        match self.phase {
+            Phase::Empty => "a number or the end of the number",
+            Phase::Multiplicand(_) => "\"cento\", a multiplier or the end of the number",
+            Phase::Hundreds(_) => "a unit, a ten, a multiplier or the end of the number",
+            Phase::Ten(_) => "a unit, a multiplier or the end of the number",
+            Phase::Closed(_) => "a multiplier or the end of the number",
+        }
+    }
+
+    pub(crate) fn push(&mut self, token: &Token, text: &str) -> Result<(), &'static str> {
+        if self.is_finished {
+            return Err(self.expected());
+        }
+        if self.elided.is_some() && !text.starts_with(['o', 'u']) {
+            return Err(self.expected());
+        }
+        if self.after_of && !matches!(token.kind, Kind::Multiplier(_, Form::Plural)) {
+            return Err(self.expected());
+        }
+        if self.is_negative && token.ending.is_some() {
+            return Err("a cardinal number after \"meno\"");
+        }
+
+        match token.kind {
+            Kind::Minus if !self.has_number && !self.is_negative => {
+                self.is_negative = true;
+                return Ok(());
+            }
+            Kind::Conjunction if self.has_number && !self.after_conjunction => {
+                self.after_conjunction = true;
+                return Ok(());
+            }
+            Kind::Of if self.after_dozen => {
+                self.after_dozen = false;
+                self.after_of = true;
+                return Ok(());
+            }
+            Kind::Half
+                if self.after_conjunction && self.phase == Phase::Empty && self.exponent < 12 =>
+            {
+                self.total += 10_i64.pow(self.exponent) / 2;
+                self.is_finished = true;
+            }
+            Kind::Dozen(form) => {
+                let Phase::Multiplicand(count) = self.phase else {
+                    return Err(self.expected());
+                };
+                if (form == Form::Singular) != (count == 1) {
+                    return Err(match form {
+                        Form::Singular => "\"dozzine\" after a number greater than 1",
+                        _ => "\"dozzina\" after \"una\"",
+                    });
+                }
+
+                self.phase = Phase::Closed(count as u16 * 12);
+                self.has_number = true;
+                self.after_dozen = true;
+                return Ok(());
+            }
+            Kind::Zero | Kind::IrregularOrdinal(_) if !self.has_number => {
+                if let Kind::IrregularOrdinal(value) = token.kind {
+                    self.phase = Phase::Closed(value as u16);
+                }
+                self.is_finished = true;
+            }
+            Kind::Unit(unit) => {
+                self.phase = match self.phase {
+                    Phase::Empty if token.ending.is_some() && !self.has_number => {
+                        return Err("\"primo\" to \"nono\" for the ordinals from 1 to 9")
+                    }
+                    Phase::Empty => Phase::Multiplicand(unit),
+                    Phase::Hundreds(value) | Phase::Ten(value) => {
+                        Phase::Closed(value + unit as u16)
+                    }
+                    _ => return Err(self.expected()),
+                }
+            }
+            Kind::Teen(teen) => {
+                self.phase = match self.phase {
+                    Phase::Empty | Phase::Hundreds(_) => {
+                        Phase::Closed(self.phase.value() + teen as u16)
+                    }
+                    _ => return Err(self.expected()),
+                }
+            }
+            Kind::Ten(ten) => {
+                self.phase = match self.phase {
+                    Phase::Empty | Phase::Hundreds(_) => {
+                        Phase::Ten(self.phase.value() + ten as u16)
+                    }
+                    _ => return Err(self.expected()),
+                }
+            }
+            Kind::Hundred => {
+                self.phase = match self.phase {
+                    Phase::Empty => Phase::Hundreds(100),
+                    Phase::Multiplicand(unit) if unit > 1 => Phase::Hundreds(unit as u16 * 100),
+                    _ => return Err(self.expected()),
+                }
+            }
+            Kind::Multiplier(exponent, form) if exponent < self.exponent => {
+                let count = self.phase.value();
+                let is_valid = match (exponent, form) {
+                    (3, Form::Singular) => count == 0,
+                    (3, Form::Plural) => count > 1,
+                    (3, Form::Any) => count != 1,
+                    (_, Form::Singular) => count <= 1,
+                    (_, Form::Plural) => count > 1,
+                    (_, Form::Any) => true,
+                };
+                if !is_valid {
+                    return Err(match form {
+                        Form::Singular if exponent == 3 => "\"mila\" after a number",
+                        Form::Singular => {
+                            "\"milioni\" or \"miliardi\" after a number greater than 1"
+                        }
+                        _ if exponent == 3 => "\"mille\" without \"un\"",
+                        _ => "\"milione\" or \"miliardo\" after \"un\"",
+                    });
+                }
+                if count == 0 && exponent > 3 && self.bare_multiplier.is_none() {
+                    self.bare_multiplier = Some(*token);
+                }
+
+                self.total += count.max(1) as i64 * 10_i64.pow(exponent);
+                self.exponent = exponent;
+                self.phase = Phase::Empty;
+            }
+            _ => return Err(self.expected()),
+        }
+
+        self.has_number = true;
+        self.after_conjunction = false;
+        self.after_dozen = false;
+        self.after_of = false;
+        self.elided = token.elided.then_some(match token.kind {
+            Kind::Hundred => "\"uno\", \"otto\", \"ottanta\" or \"undici\" after \"cent\"",
+            _ => "\"uno\" or \"otto\" after an elided word",
+        });
+        if token.ending.is_some() {
+            self.ending = token.ending;
+            self.is_finished = true;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn finish(&self) -> Result<ParsedNumber, &'static str> {
+        if self.after_conjunction || self.after_of || self.elided.is_some() || !self.has_number {
+            return Err(self.expected());
+        }
+
+        let value = self.total + self.phase.value() as i64;
+        Ok(ParsedNumber {
+            value: if self.is_negative { -value } else { value },
+            ending: self.ending,
+            is_feminine: false,
+        })
+    }
+}
+
+/// Returns the connector read as a whole word, "e" or "di", which would otherwise be part of longer words.
+pub(crate) fn connector(chunk: &str) -> Option<Token> {
+    let kind = match chunk {
+        CONJUNCTION => Kind::Conjunction,
+        OF => Kind::Of,
+        _ => return None,
+    };
+
+    Some(Token {
+        kind,
+        start: 0,
+        end: chunk.len(),
+        elided: false,
+        ending: None,
+    })
+}
+
+fn offset(token: &Token, start: usize) -> Token {
+    Token {
+        start: start + token.start,
+        end: start + token.end,
+        ..*token
+    }
+}
+
+pub(crate) fn parse(word: &str) -> Result<ParsedNumber, ItalianError> {
+    let Normalized {
+        text, positions, ..
+    } = normalize(word);
+    let lexicon = lexicon();
+    let mut grammar = Grammar::new();
+
+    let error = |start: usize, end: usize, expected: &'static str| ItalianError {
+        start: positions[start],
+        end: positions[end],
+        token: word[positions[start]..positions[end]].to_string(),
+        expected,
+    };
+
+    let mut is_feminine = false;
+    let mut chunk_start = None;
+    for (position, letter) in text.char_indices().chain([(text.len(), ' ')]) {
+        if !letter.is_whitespace() {
+            chunk_start.get_or_insert(position);
+            continue;
+        }
+        let Some(start) = chunk_start.take() else {
+            continue;
+        };
+
+        let chunk = &text[start..position];
+        let tokens = match connector(chunk) {
+            Some(token) => vec![token],
+            None => match segment(chunk, lexicon) {
+                Ok(tokens) => tokens,
+                Err((furthest, best)) => {
+                    for token in &best {
+                        let token = offset(token, start);
+                        grammar
+                            .push(&token, &text[token.start..])
+                            .map_err(|expected| error(token.start, token.end, expected))?;
+                    }
+                    return Err(error(start + furthest, position, grammar.expected()));
+                }
+            },
+        };
+
+        for token in &tokens {
+            // The tokens are moved from their chunk to the whole text, so the grammar keeps absolute spans.
+            let token = offset(token, start);
+            grammar
+                .push(&token, &text[token.start..])
+                .map_err(|expected| error(token.start, token.end, expected))?;
+            is_feminine = &text[token.start..token.end] == "una";
+        }
+    }
+
+    let mut number = grammar
+        .finish()
+        .map_err(|expected| error(text.len(), text.len(), expected))?;
+
+    if number.ending.is_none() {
+        if let Some(token) = grammar.bare_multiplier {
+            return Err(error(token.start, token.end, "\"un\" before it"));
+        }
+    }
+
+    number.is_feminine = is_feminine;
+    Ok(number)
+}
+
+/// Converts an Italian word representation to a number, reporting where and why it is invalid
+///
+/// # Arguments
+///
+/// * `word` - The Italian word to convert, cardinal or ordinal, e.g. "ventitré" or "centesimo".
+///
+/// The words are read with a grammar of units, tens (with elision, "ventuno"), hundreds, "mille"/"mila",
+/// "milioni", "miliardi" and ordinal suffixes, plus the informal "una", "e mezzo" and "una dozzina di migliaia".
+/// Spaces and the conjunction "e" between words are allowed, and the word is first normalized as
+/// `italian_normalizer` does, so "VENTITRÈ" and "venti-tre'" are read as "ventitré".
+/// On failure, the error holds the byte span of the first invalid word and what was expected there.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::italian_parser;
+///
+/// let result = italian_parser("un milione e trecentoventuno");
+/// assert_eq!(Ok(1_000_321), result);
+///
+/// let result = italian_parser("centottantesimo");
+/// assert_eq!(Ok(180), result);
+///
+/// let error = italian_parser("ventottobla").unwrap_err();
+/// assert_eq!((8, 11), (error.start(), error.end()));
+/// assert_eq!("bla", error.token());
+/// assert_eq!("a multiplier or the end of the number", error.expected());
+///
+/// let error = italian_parser("centozero").unwrap_err();
+/// assert_eq!("zero", error.token());
+/// ```
+pub fn italian_parser(word: &str) -> Result<i64, ItalianError> {
+    parse(word).map(|number| number.value)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::italian_parser;
+    use crate::{cardinal_converter, italian_converter, ordinal_converter, Options};
+
+    fn error(word: &str) -> (usize, usize, String, &'static str) {
+        let error = italian_parser(word).unwrap_err();
+        (
+            error.start(),
+            error.end(),
+            error.token().to_string(),
+            error.expected(),
+        )
+    }
+
+    mod cardinal {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let numbers = (0..=1_200)
+                .chain((1_000..=1_000_000_000).step_by(999_983))
+                .chain([1_000_001, 21_000_000, 999_999_999_999]);
+
+            for number in numbers {
+                let word = cardinal_converter(number as f64, false).unwrap();
+                assert_eq!(italian_parser(&word), Ok(number), "{}", word);
+            }
+        }
+
+        #[test]
+        fn test_elision() {
+            assert_eq!(italian_parser("ventuno"), Ok(21));
+            assert_eq!(italian_parser("ventiuno"), Ok(21));
+            assert_eq!(italian_parser("centottanta"), Ok(180));
+            assert_eq!(italian_parser("centoottanta"), Ok(180));
+        }
+
+        #[test]
+        fn test_spaces_and_conjunctions() {
+            assert_eq!(italian_parser("cento e tre"), Ok(103));
+            assert_eq!(italian_parser("Tre Milioni E Trentatré"), Ok(3_000_033));
+        }
+
+        #[test]
+        fn test_negative() {
+            assert_eq!(italian_parser("meno ventitré"), Ok(-23));
+        }
+    }
+
+    mod ordinal {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let numbers = (1..=1_200).chain((1_000..=999_999).step_by(9_973)).chain([
+                1_000_001,
+                1_001_000,
+                21_000_000,
+                1_000_000_001,
+                2_000_000_021,
+            ]);
+
+            for number in numbers {
+                for options in [None, Some(Options::new(true, true))] {
+                    let word = ordinal_converter(number, options).unwrap();
+                    assert_eq!(italian_parser(&word), Ok(number as i64), "{}", word);
+                }
+            }
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_long_input() {
+            let word = format!("{}x", "tre".repeat(50_000));
+            assert_eq!(error(&word).0, 3);
+            assert_eq!(italian_converter(word.clone()), Err("invalid word number"));
+
+            let word = format!("{}x", "trentatre".repeat(20_000));
+            assert!(italian_parser(&word).is_err());
+        }
+
+        #[test]
+        fn test_unknown_word() {
+            assert_eq!(
+                error("ventottobla"),
+                (
+                    8,
+                    11,
+                    String::from("bla"),
+                    "a multiplier or the end of the number"
+                )
+            );
+            assert_eq!(error("blabla"), (0, 6, String::from("blabla"), "a number"));
+        }
+
+        #[test]
+        fn test_unexpected_word() {
+            assert_eq!(
+                error("centozero"),
+                (
+                    5,
+                    9,
+                    String::from("zero"),
+                    "a unit, a ten, a multiplier or the end of the number"
+                )
+            );
+            assert_eq!(
+                error("venti trenta"),
+                (
+                    6,
+                    12,
+                    String::from("trenta"),
+                    "a unit, a multiplier or the end of the number"
+                )
+            );
+        }
+
+        #[test]
+        fn test_multipliers() {
+            assert_eq!(
+                error("unmila"),
+                (2, 6, String::from("mila"), "\"mille\" without \"un\"")
+            );
+            assert_eq!(
+                error("duemille"),
+                (3, 8, String::from("mille"), "\"mila\" after a number")
+            );
+            assert_eq!(
+                error("mila milioni"),
+                (0, 4, String::from("mila"), "\"mille\" without \"un\"")
+            );
+            assert_eq!(
+                error("mille milioni"),
+                (
+                    6,
+                    13,
+                    String::from("milioni"),
+                    "a number or the end of the number"
+                )
+            );
+            assert_eq!(
+                error("milione"),
+                (0, 7, String::from("milione"), "\"un\" before it")
+            );
+            assert_eq!(
+                error("tre miliardi milione"),
+                (13, 20, String::from("milione"), "\"un\" before it")
+            );
+            assert_eq!(
+                error("due miliardi e miliardo"),
+                (15, 23, String::from("miliardo"), "a number")
+            );
+        }
+
+        #[test]
+        fn test_negative_ordinals() {
+            for (word, start, end, token) in [
+                ("meno primo", 5, 10, "primo"),
+                ("meno ventesimo", 5, 14, "ventesimo"),
+                ("meno zeresimo", 5, 13, "zeresimo"),
+                ("meno mille duecentesima", 14, 23, "centesima"),
+            ] {
+                assert_eq!(
+                    error(word),
+                    (
+                        start,
+                        end,
+                        String::from(token),
+                        "a cardinal number after \"meno\""
+                    ),
+                    "{}",
+                    word
+                );
+            }
+            assert_eq!(
+                italian_converter(String::from("meno primo")),
+                Err("invalid word number")
+            );
+        }
+
+        #[test]
+        fn test_elision() {
+            assert_eq!(
+                error("ventdue"),
+                (
+                    4,
+                    7,
+                    String::from("due"),
+                    "\"uno\" or \"otto\" after an elided word"
+                )
+            );
+            assert_eq!(
+                error("vent"),
+                (
+                    4,
+                    4,
+                    String::new(),
+                    "\"uno\" or \"otto\" after an elided word"
+                )
+            );
+        }
+
+        #[test]
+        fn test_ordinals() {
+            assert_eq!(
+                error("duesimo"),
+                (
+                    0,
+                    7,
+                    String::from("duesimo"),
+                    "\"primo\" to \"nono\" for the ordinals from 1 to 9"
+                )
+            );
+            assert_eq!(
+                error("ventesimo primo"),
+                (10, 15, String::from("primo"), "the end of the number")
+            );
+        }
+
+        #[test]
+        fn test_conjunction() {
+            assert_eq!(error("e tre"), (0, 1, String::from("e"), "a number"));
+            assert_eq!(error("tre e"), (5, 5, String::new(), "a number"));
+        }
+
+        #[test]
+        fn test_uppercase_span() {
+            assert_eq!(
+                error("VENTOTTOBLA"),
+                (
+                    8,
+                    11,
+                    String::from("BLA"),
+                    "a multiplier or the end of the number"
+                )
+            );
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(
+                italian_parser("centozero").unwrap_err().to_string(),
+                "unexpected \"zero\" at 5..9, expected a unit, a ten, a multiplier or the end of the number"
+            );
+        }
+    }
+}
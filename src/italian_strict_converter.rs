@@ -0,0 +1,250 @@
+use std::fmt;
+
+use crate::italian_parser::{parse, ParsedNumber};
+use crate::ordinal_indicator_converter::gender_and_number;
+use crate::{cardinal_converter, ordinal_converter, ItalianError};
+
+/// An error returned by `italian_strict_converter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItalianSpellingError {
+    /// The word is not an Italian number.
+    Invalid(ItalianError),
+    /// The word is an Italian number, but not written as `cardinal_converter` or `ordinal_converter` would.
+    NonCanonical {
+        /// The value read from the word.
+        value: i64,
+        /// The canonical spelling of the value.
+        expected: String,
+    },
+    /// The word is an Italian number, but `cardinal_converter` and `ordinal_converter` cannot write its value.
+    Unspellable {
+        /// The value read from the word.
+        value: i64,
+        /// The error of the converter.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ItalianSpellingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItalianSpellingError::Invalid(error) => error.fmt(f),
+            ItalianSpellingError::NonCanonical { expected, .. } => {
+                write!(f, "non-canonical spelling, expected \"{}\"", expected)
+            }
+            ItalianSpellingError::Unspellable { value, reason } => {
+                write!(f, "no canonical spelling for {}: {}", value, reason)
+            }
+        }
+    }
+}
+
+pub(crate) fn canonical_spelling(value: i64, ending: Option<char>) -> Result<String, &'static str> {
+    let Some(ending) = ending else {
+        return cardinal_converter(value as f64, false);
+    };
+
+    let value = u64::try_from(value).map_err(|_| "lower than 0")?;
+    ordinal_converter(value, Some(gender_and_number(ending)))
+}
+
+/// Converts an Italian word representation to a number, accepting only the canonical spelling
+///
+/// # Arguments
+///
+/// * `word` - The Italian word to convert, cardinal or ordinal. The comparison ignores case.
+///
+/// The canonical spelling is the one returned by `cardinal_converter`, or by `ordinal_converter` with the gender and
+/// plurality of the word. A valid number with another spelling, like "ventiuno" or "trentatre", is rejected with
+/// its canonical spelling.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{italian_strict_converter, ItalianSpellingError};
+///
+/// let result = italian_strict_converter("trentatré");
+/// assert_eq!(Ok(33), result);
+///
+/// let result = italian_strict_converter("ventiuno");
+/// assert_eq!(
+///     Err(ItalianSpellingError::NonCanonical { value: 21, expected: String::from("ventuno") }),
+///     result
+/// );
+///
+/// let result = italian_strict_converter("centoottesima");
+/// assert_eq!(
+///     Err(ItalianSpellingError::NonCanonical { value: 108, expected: String::from("centottesima") }),
+///     result
+/// );
+/// ```
+pub fn italian_strict_converter(word: &str) -> Result<i64, ItalianSpellingError> {
+    let number = parse(word).map_err(ItalianSpellingError::Invalid)?;
+
+    spelling_checker(word, number)
+}
+
+fn spelling_checker(word: &str, number: ParsedNumber) -> Result<i64, ItalianSpellingError> {
+    let expected = canonical_spelling(number.value, number.ending).map_err(|reason| {
+        ItalianSpellingError::Unspellable {
+            value: number.value,
+            reason,
+        }
+    })?;
+
+    if word.to_lowercase() != expected {
+        return Err(ItalianSpellingError::NonCanonical {
+            value: number.value,
+            expected,
+        });
+    }
+
+    Ok(number.value)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{italian_strict_converter, spelling_checker, ItalianSpellingError};
+    use crate::italian_parser::ParsedNumber;
+    use crate::{cardinal_converter, ordinal_converter, Options};
+
+    fn expected(word: &str) -> Option<String> {
+        match italian_strict_converter(word) {
+            Err(ItalianSpellingError::NonCanonical { expected, .. }) => Some(expected),
+            _ => None,
+        }
+    }
+
+    mod canonical {
+        use super::*;
+
+        #[test]
+        fn test_cardinal_round_trip() {
+            for number in (-30..=1_200).chain([1_000_001, 21_000_000, 2_000_000_021]) {
+                let word = cardinal_converter(number as f64, false).unwrap();
+                assert_eq!(italian_strict_converter(&word), Ok(number), "{}", word);
+            }
+        }
+
+        #[test]
+        fn test_ordinal_round_trip() {
+            let options = [
+                Options::new(false, false),
+                Options::new(true, false),
+                Options::new(false, true),
+                Options::new(true, true),
+            ];
+
+            for number in (0..=120)
+                .chain((121..=1_200).step_by(13))
+                .chain([1_000_001, 1_001_000])
+            {
+                for options in options {
+                    let word = ordinal_converter(number, Some(options)).unwrap();
+                    assert_eq!(
+                        italian_strict_converter(&word),
+                        Ok(number as i64),
+                        "{}",
+                        word
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_case() {
+            assert_eq!(italian_strict_converter("Ventuno"), Ok(21));
+        }
+    }
+
+    mod non_canonical {
+        use super::*;
+
+        #[test]
+        fn test_elision() {
+            assert_eq!(expected("ventiuno"), Some(String::from("ventuno")));
+            assert_eq!(expected("centoottanta"), Some(String::from("centottanta")));
+            assert_eq!(expected("ventiunesimo"), Some(String::from("ventunesimo")));
+        }
+
+        #[test]
+        fn test_accent() {
+            assert_eq!(expected("trentatre"), Some(String::from("trentatré")));
+            assert_eq!(expected("tré"), Some(String::from("tre")));
+        }
+
+        #[test]
+        fn test_spaces_and_conjunctions() {
+            assert_eq!(expected("cento e tre"), Some(String::from("centotré")));
+            assert_eq!(
+                expected("un milione uno"),
+                Some(String::from("un milione e uno"))
+            );
+        }
+
+        #[test]
+        fn test_value() {
+            assert_eq!(
+                italian_strict_converter("ventiuno"),
+                Err(ItalianSpellingError::NonCanonical {
+                    value: 21,
+                    expected: String::from("ventuno")
+                })
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid() {
+            let error = italian_strict_converter("ventottobla").unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                "unexpected \"bla\" at 8..11, expected a multiplier or the end of the number"
+            );
+        }
+
+        #[test]
+        fn test_negative_ordinal() {
+            assert!(matches!(
+                italian_strict_converter("meno ventesimo"),
+                Err(ItalianSpellingError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn test_unspellable() {
+            let number = ParsedNumber {
+                value: -20,
+                ending: Some('o'),
+                is_feminine: false,
+            };
+            let error = spelling_checker("meno ventesimo", number).unwrap_err();
+            assert_eq!(
+                error,
+                ItalianSpellingError::Unspellable {
+                    value: -20,
+                    reason: "lower than 0"
+                }
+            );
+            assert_eq!(
+                error.to_string(),
+                "no canonical spelling for -20: lower than 0"
+            );
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(
+                italian_strict_converter("trentatre")
+                    .unwrap_err()
+                    .to_string(),
+                "non-canonical spelling, expected \"trentatré\""
+            );
+        }
+    }
+}
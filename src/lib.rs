@@ -3,10 +3,29 @@
 //! `italian_numbers` provides a collection of functions to convert numbers to Italian word representations, to Roman number representations, and vice versa.
 
 mod arabic_converter;
+mod batch_converter;
+mod calendar_resolver;
 mod cardinal_converter;
+mod digits_converter;
+mod hybrid_converter;
 mod italian_converter;
+mod italian_fuzzy_converter;
+mod italian_normalizer;
+mod italian_number_parser;
+mod italian_parser;
+mod italian_strict_converter;
+mod list_converter;
 mod ordinal_converter;
+mod ordinal_indicator_converter;
 mod roman_converter;
+mod roman_date_converter;
+mod roman_fraction_converter;
+mod roman_lenient_converter;
+mod roman_number;
+mod roman_ordinal_converter;
+mod roman_scanner;
+mod roman_style_converter;
+mod roman_validator;
 
 pub(crate) const ZERO_NINETEEN: [&str; 20] = [
     "zero",
@@ -67,8 +86,117 @@ pub(crate) const ROMAN_THOUSANDS: [&str; 4] = ["", "M", "MM", "MMM"];
 
 pub(crate) const ROMAN_LETTERS: [&str; 7] = ["I", "V", "X", "L", "C", "D", "M"];
 
+pub(crate) const ROMAN_UNICODE_LETTERS: [char; 7] = ['Ⅰ', 'Ⅴ', 'Ⅹ', 'Ⅼ', 'Ⅽ', 'Ⅾ', 'Ⅿ'];
+
+pub(crate) const ROMAN_UNICODE_NUMERALS: [(char, u16); 16] = [
+    ('Ⅰ', 1),
+    ('Ⅱ', 2),
+    ('Ⅲ', 3),
+    ('Ⅳ', 4),
+    ('Ⅴ', 5),
+    ('Ⅵ', 6),
+    ('Ⅶ', 7),
+    ('Ⅷ', 8),
+    ('Ⅸ', 9),
+    ('Ⅹ', 10),
+    ('Ⅺ', 11),
+    ('Ⅻ', 12),
+    ('Ⅼ', 50),
+    ('Ⅽ', 100),
+    ('Ⅾ', 500),
+    ('Ⅿ', 1000),
+];
+
+pub(crate) const WEEKDAYS: [&str; 7] = [
+    "lunedì",
+    "martedì",
+    "mercoledì",
+    "giovedì",
+    "venerdì",
+    "sabato",
+    "domenica",
+];
+
+pub(crate) const MONTHS: [&str; 12] = [
+    "gennaio",
+    "febbraio",
+    "marzo",
+    "aprile",
+    "maggio",
+    "giugno",
+    "luglio",
+    "agosto",
+    "settembre",
+    "ottobre",
+    "novembre",
+    "dicembre",
+];
+
+pub(crate) const LATIN_MONTHS_ABLATIVE: [&str; 12] = [
+    "Ianuariis",
+    "Februariis",
+    "Martiis",
+    "Aprilibus",
+    "Maiis",
+    "Iuniis",
+    "Iuliis",
+    "Augustis",
+    "Septembribus",
+    "Octobribus",
+    "Novembribus",
+    "Decembribus",
+];
+
+pub(crate) const LATIN_MONTHS_ACCUSATIVE: [&str; 12] = [
+    "Ianuarias",
+    "Februarias",
+    "Martias",
+    "Apriles",
+    "Maias",
+    "Iunias",
+    "Iulias",
+    "Augustas",
+    "Septembres",
+    "Octobres",
+    "Novembres",
+    "Decembres",
+];
+
 pub use arabic_converter::arabic_converter;
+pub use batch_converter::{
+    cardinal_batch_converter, italian_batch_converter, ordinal_batch_converter,
+};
+pub use calendar_resolver::{calendar_resolver, CalendarDate};
 pub use cardinal_converter::cardinal_converter;
+pub use digits_converter::{
+    digits_cardinal_converter, digits_converter, digits_formatter, DigitGrouping,
+};
+pub use hybrid_converter::{hybrid_converter, hybrid_formatter, HybridOptions};
 pub use italian_converter::italian_converter;
+pub use italian_fuzzy_converter::{italian_fuzzy_converter, ItalianCorrection};
+pub use italian_normalizer::{italian_normalizer, ItalianNormalization};
+pub use italian_number_parser::{italian_number_parser, ItalianKind, ItalianNumber};
+pub use italian_parser::{italian_parser, ItalianError};
+pub use italian_strict_converter::{italian_strict_converter, ItalianSpellingError};
+pub use list_converter::{list_index_converter, list_marker_converter, ListStyle};
 pub use ordinal_converter::{ordinal_converter, Options};
+pub use ordinal_indicator_converter::{
+    ordinal_indicator_converter, ordinal_indicator_parser, OrdinalIndicator,
+};
 pub use roman_converter::roman_converter;
+pub use roman_date_converter::{arabic_date_converter, roman_date_converter};
+pub use roman_fraction_converter::{
+    arabic_fraction_converter, arabic_sextula_converter, arabic_zero_converter,
+    roman_fraction_converter, roman_sextula_converter, roman_zero_converter, RomanFraction,
+};
+pub use roman_lenient_converter::{
+    arabic_lenient_converter, RomanParsing, RomanReading, RomanWarning,
+};
+pub use roman_number::Roman;
+pub use roman_ordinal_converter::{ordinal_roman_converter, roman_ordinal_converter};
+pub use roman_scanner::{roman_scanner, RomanMatch};
+pub use roman_style_converter::{
+    arabic_case_converter, arabic_style_converter, roman_case_converter, roman_style_converter,
+    RomanCase, RomanStyle,
+};
+pub use roman_validator::{roman_canonicalizer, roman_validator, RomanRule, RomanViolation};
@@ -0,0 +1,377 @@
+use crate::{
+    arabic_converter, cardinal_converter, italian_converter, ordinal_converter, roman_converter,
+    Options,
+};
+
+const ALPHABET_LENGTH: u64 = 26;
+
+/// How `list_marker_converter` writes the marker of a list item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListStyle {
+    /// 1, 2, 3 … 10, 11.
+    Decimal,
+    /// a, b, c … z, aa, ab.
+    LowerAlpha,
+    /// A, B, C … Z, AA, AB.
+    UpperAlpha,
+    /// i, ii, iii … up to 3999.
+    LowerRoman,
+    /// I, II, III … up to 3999.
+    UpperRoman,
+    /// uno, due, tre.
+    Cardinal,
+    /// primo, secondo, terzo, or the gender and plurality given by the options.
+    Ordinal(Option<Options>),
+}
+
+fn alpha_converter(index: u64, uppercase: bool) -> String {
+    let first = if uppercase { b'A' } else { b'a' };
+    let mut letters = Vec::new();
+    let mut index = index;
+
+    while index > 0 {
+        index -= 1;
+        letters.push((first + (index % ALPHABET_LENGTH) as u8) as char);
+        index /= ALPHABET_LENGTH;
+    }
+
+    letters.iter().rev().collect()
+}
+
+fn alpha_parser(marker: &str, uppercase: bool) -> Result<u64, &'static str> {
+    if marker.is_empty() {
+        return Err("invalid list marker");
+    }
+
+    marker.chars().try_fold(0_u64, |index, letter| {
+        let is_valid = if uppercase {
+            letter.is_ascii_uppercase()
+        } else {
+            letter.is_ascii_lowercase()
+        };
+        if !is_valid {
+            return Err("invalid list marker");
+        }
+
+        let value = letter.to_ascii_lowercase() as u64 - 'a' as u64 + 1;
+        index
+            .checked_mul(ALPHABET_LENGTH)
+            .and_then(|index| index.checked_add(value))
+            .ok_or("invalid list marker")
+    })
+}
+
+fn strip_punctuation(marker: &str) -> &str {
+    let marker = marker.trim();
+    let marker = marker.strip_prefix('(').unwrap_or(marker);
+
+    marker.strip_suffix(['.', ')']).unwrap_or(marker)
+}
+
+/// Converts the index of a list item to its marker
+///
+/// # Arguments
+///
+/// * `index` - The index of the item, starting from 1.
+/// * `style` - How the marker is written.
+///
+/// The marker does not include any punctuation, so "a)" or "I." is built by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{list_marker_converter, ListStyle, Options};
+///
+/// let result = list_marker_converter(4, ListStyle::UpperRoman);
+/// assert_eq!(Ok(String::from("IV")), result);
+///
+/// let result = list_marker_converter(28, ListStyle::LowerAlpha);
+/// assert_eq!(Ok(String::from("ab")), result);
+///
+/// let result = list_marker_converter(2, ListStyle::Ordinal(None));
+/// assert_eq!(Ok(String::from("secondo")), result);
+///
+/// let result = list_marker_converter(3, ListStyle::Ordinal(Some(Options::new(true, false))));
+/// assert_eq!(Ok(String::from("terza")), result);
+/// ```
+pub fn list_marker_converter(index: u64, style: ListStyle) -> Result<String, &'static str> {
+    if index < 1 {
+        return Err("lower than 1");
+    }
+
+    match style {
+        ListStyle::Decimal => Ok(index.to_string()),
+        ListStyle::LowerAlpha => Ok(alpha_converter(index, false)),
+        ListStyle::UpperAlpha => Ok(alpha_converter(index, true)),
+        ListStyle::LowerRoman | ListStyle::UpperRoman => {
+            let index = u16::try_from(index).map_err(|_| "greater than 3999")?;
+            let roman_number = roman_converter(index)?;
+
+            if style == ListStyle::LowerRoman {
+                return Ok(roman_number.to_lowercase());
+            }
+            Ok(roman_number)
+        }
+        ListStyle::Cardinal => cardinal_converter(index as f64, false),
+        ListStyle::Ordinal(options) => ordinal_converter(index, options),
+    }
+}
+
+/// Converts the marker of a list item to its index
+///
+/// # Arguments
+///
+/// * `marker` - The marker to convert. An opening parenthesis before it and a period or closing parenthesis after it
+///   are ignored, so "a)", "(a)" and "a." are all read as "a".
+/// * `style` - How the marker is written. Letters and Roman numbers must have the case of the style, while words are
+///   case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{list_index_converter, ListStyle};
+///
+/// let result = list_index_converter("IV.", ListStyle::UpperRoman);
+/// assert_eq!(Ok(4), result);
+///
+/// let result = list_index_converter("ab)", ListStyle::LowerAlpha);
+/// assert_eq!(Ok(28), result);
+///
+/// let result = list_index_converter("Secondo", ListStyle::Ordinal(None));
+/// assert_eq!(Ok(2), result);
+/// ```
+pub fn list_index_converter(marker: &str, style: ListStyle) -> Result<u64, &'static str> {
+    let marker = strip_punctuation(marker);
+
+    let index = match style {
+        ListStyle::Decimal => {
+            if marker.is_empty() || !marker.chars().all(|letter| letter.is_ascii_digit()) {
+                return Err("invalid list marker");
+            }
+            marker.parse().map_err(|_| "invalid list marker")?
+        }
+        ListStyle::LowerAlpha => alpha_parser(marker, false)?,
+        ListStyle::UpperAlpha => alpha_parser(marker, true)?,
+        ListStyle::LowerRoman | ListStyle::UpperRoman => {
+            let roman_number = marker.to_uppercase();
+            let expected = if style == ListStyle::LowerRoman {
+                roman_number.to_lowercase()
+            } else {
+                roman_number.clone()
+            };
+            if marker != expected {
+                return Err("invalid list marker");
+            }
+
+            arabic_converter(roman_number).map_err(|_| "invalid list marker")? as u64
+        }
+        ListStyle::Cardinal | ListStyle::Ordinal(_) => {
+            let word = marker.to_lowercase();
+            let index = italian_converter(word.clone())
+                .ok()
+                .and_then(|index| u64::try_from(index).ok())
+                .ok_or("invalid list marker")?;
+
+            if list_marker_converter(index, style).ok() != Some(word) {
+                return Err("invalid list marker");
+            }
+            index
+        }
+    };
+
+    if index < 1 {
+        return Err("invalid list marker");
+    }
+
+    Ok(index)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{list_index_converter, list_marker_converter, ListStyle};
+    use crate::Options;
+
+    mod markers {
+        use super::*;
+
+        #[test]
+        fn test_decimal() {
+            assert_eq!(
+                list_marker_converter(12, ListStyle::Decimal),
+                Ok(String::from("12"))
+            );
+        }
+
+        #[test]
+        fn test_alpha() {
+            assert_eq!(
+                list_marker_converter(1, ListStyle::LowerAlpha),
+                Ok(String::from("a"))
+            );
+            assert_eq!(
+                list_marker_converter(26, ListStyle::LowerAlpha),
+                Ok(String::from("z"))
+            );
+            assert_eq!(
+                list_marker_converter(27, ListStyle::UpperAlpha),
+                Ok(String::from("AA"))
+            );
+            assert_eq!(
+                list_marker_converter(702, ListStyle::UpperAlpha),
+                Ok(String::from("ZZ"))
+            );
+            assert_eq!(
+                list_marker_converter(703, ListStyle::LowerAlpha),
+                Ok(String::from("aaa"))
+            );
+        }
+
+        #[test]
+        fn test_roman() {
+            assert_eq!(
+                list_marker_converter(9, ListStyle::LowerRoman),
+                Ok(String::from("ix"))
+            );
+            assert_eq!(
+                list_marker_converter(1_990, ListStyle::UpperRoman),
+                Ok(String::from("MCMXC"))
+            );
+        }
+
+        #[test]
+        fn test_cardinal() {
+            assert_eq!(
+                list_marker_converter(21, ListStyle::Cardinal),
+                Ok(String::from("ventuno"))
+            );
+        }
+
+        #[test]
+        fn test_ordinal() {
+            assert_eq!(
+                list_marker_converter(1, ListStyle::Ordinal(None)),
+                Ok(String::from("primo"))
+            );
+            assert_eq!(
+                list_marker_converter(11, ListStyle::Ordinal(Some(Options::new(true, false)))),
+                Ok(String::from("undicesima"))
+            );
+        }
+    }
+
+    mod indexes {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let styles = [
+                ListStyle::Decimal,
+                ListStyle::LowerAlpha,
+                ListStyle::UpperAlpha,
+                ListStyle::LowerRoman,
+                ListStyle::UpperRoman,
+            ];
+
+            for style in styles {
+                for index in 1..=3_999 {
+                    let marker = list_marker_converter(index, style).unwrap();
+                    assert_eq!(list_index_converter(&marker, style), Ok(index));
+                }
+            }
+        }
+
+        #[test]
+        fn test_words_round_trip() {
+            let styles = [
+                ListStyle::Cardinal,
+                ListStyle::Ordinal(None),
+                ListStyle::Ordinal(Some(Options::new(true, true))),
+            ];
+
+            for style in styles {
+                for index in (1..=30).chain([99, 100, 101, 1_000, 1_001, 2_024]) {
+                    let marker = list_marker_converter(index, style).unwrap();
+                    assert_eq!(list_index_converter(&marker, style), Ok(index));
+                }
+            }
+        }
+
+        #[test]
+        fn test_punctuation() {
+            assert_eq!(list_index_converter("a)", ListStyle::LowerAlpha), Ok(1));
+            assert_eq!(list_index_converter("(iv)", ListStyle::LowerRoman), Ok(4));
+            assert_eq!(list_index_converter("12.", ListStyle::Decimal), Ok(12));
+        }
+
+        #[test]
+        fn test_case_insensitive_words() {
+            assert_eq!(
+                list_index_converter("Primo", ListStyle::Ordinal(None)),
+                Ok(1)
+            );
+            assert_eq!(list_index_converter("TRE", ListStyle::Cardinal), Ok(3));
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_lower_than_1() {
+            assert_eq!(
+                list_marker_converter(0, ListStyle::Decimal),
+                Err("lower than 1")
+            );
+            assert_eq!(
+                list_index_converter("0", ListStyle::Decimal),
+                Err("invalid list marker")
+            );
+        }
+
+        #[test]
+        fn test_greater_than_3999() {
+            assert_eq!(
+                list_marker_converter(4_000, ListStyle::UpperRoman),
+                Err("greater than 3999")
+            );
+        }
+
+        #[test]
+        fn test_wrong_case() {
+            assert_eq!(
+                list_index_converter("A", ListStyle::LowerAlpha),
+                Err("invalid list marker")
+            );
+            assert_eq!(
+                list_index_converter("iv", ListStyle::UpperRoman),
+                Err("invalid list marker")
+            );
+        }
+
+        #[test]
+        fn test_wrong_style() {
+            assert_eq!(
+                list_index_converter("primo", ListStyle::Cardinal),
+                Err("invalid list marker")
+            );
+            assert_eq!(
+                list_index_converter("prima", ListStyle::Ordinal(None)),
+                Err("invalid list marker")
+            );
+            assert_eq!(
+                list_index_converter("-3", ListStyle::Decimal),
+                Err("invalid list marker")
+            );
+        }
+
+        #[test]
+        fn test_overflow() {
+            assert_eq!(
+                list_index_converter("zzzzzzzzzzzzzzzzzzzz", ListStyle::LowerAlpha),
+                Err("invalid list marker")
+            );
+        }
+    }
+}
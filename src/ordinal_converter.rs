@@ -1,6 +1,6 @@
 use crate::{cardinal_converter, ZERO_TEN_ORDINALS};
-use regex::{Captures, Regex};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Options {
     female: bool,
     plural: bool,
@@ -36,6 +36,30 @@ impl Options {
     }
 }
 
+/// Joins an "i" or "o" ending a word to a following word starting with "o" or "u", keeping only one vowel:
+/// the first before "o" and the second before "u".
+fn elide_vowels(word: &str) -> String {
+    let letters = word.chars().collect::<Vec<_>>();
+    let mut elided = String::with_capacity(word.len());
+    let mut index = 0;
+
+    while index < letters.len() {
+        match letters[index..] {
+            [first @ ('i' | 'o'), space, second @ ('o' | 'u'), ..] if space.is_whitespace() => {
+                elided.push(if second == 'o' { first } else { second });
+                index += 3;
+            }
+            [letter, ..] => {
+                elided.push(letter);
+                index += 1;
+            }
+            [] => break,
+        }
+    }
+
+    elided
+}
+
 fn converter(number: u64) -> Result<String, &'static str> {
     if number < 11 {
         return Ok(ZERO_TEN_ORDINALS[number as usize].to_string());
@@ -44,27 +68,11 @@ fn converter(number: u64) -> Result<String, &'static str> {
     let mut word = cardinal_converter(number as f64, false)?;
 
     word = word.replace(" e ", " ");
-    word = Regex::new(r"([io])\s([ou])")
-        .unwrap()
-        .replace_all(&word, |caps: &Captures| {
-            if &caps[2] == "o" {
-                return caps[1].to_string();
-            }
-            if &caps[2] == "u" {
-                return caps[2].to_string();
-            }
-            "".to_string()
-        })
-        .to_string();
+    word = elide_vowels(&word);
     word = word.replace("un ", "");
-    word = Regex::new(r"ouno$")
-        .unwrap()
-        .replace_all(&word, "uno")
-        .to_string();
-    word = Regex::new(r"ootto$")
-        .unwrap()
-        .replace_all(&word, "otto")
-        .to_string();
+    if word.ends_with("ouno") || word.ends_with("ootto") {
+        word.remove(word.len() - 4);
+    }
     word = word.replace(' ', "");
 
     let last_digit = number % 10;
@@ -153,8 +161,8 @@ fn converter(number: u64) -> Result<String, &'static str> {
 pub fn ordinal_converter(number: u64, options: Option<Options>) -> Result<String, &'static str> {
     let mut result = converter(number)?;
 
-    let female = options.as_ref().map_or(false, |o| o.female);
-    let plural = options.as_ref().map_or(false, |o| o.plural);
+    let female = options.as_ref().is_some_and(|o| o.female);
+    let plural = options.as_ref().is_some_and(|o| o.plural);
 
     if female && plural {
         result.pop();
@@ -0,0 +1,334 @@
+use crate::{arabic_converter, Options};
+
+const SUPERSCRIPT: (&str, &str) = ("<sup>", "</sup>");
+
+/// How `ordinal_indicator_converter` writes the indicator after the digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrdinalIndicator {
+    /// 1º, 1ª, 2i, 3e, with the masculine and feminine ordinal indicators.
+    Ordinal,
+    /// 1°, 1ª, 2i, 3e, with the degree sign for the masculine singular.
+    Degree,
+    /// 1o, 1a, 2i, 3e, with plain letters.
+    Letter,
+    /// 1<sup>o</sup>, 1<sup>a</sup>, 2<sup>i</sup>, 3<sup>e</sup>, for HTML.
+    Html,
+}
+
+/// Returns the gender and plurality of an ordinal ending in "o", "a", "i" or "e".
+pub(crate) fn gender_and_number(ending: char) -> Options {
+    Options::new(matches!(ending, 'a' | 'e'), matches!(ending, 'i' | 'e'))
+}
+
+/// The consonant before the final vowel of the ordinals from "zeresimo" to "decimo", used in informal short
+/// ordinals like "2do" or "8vo". The ordinals above ten end in "esimo", so they use "m", as in "11mo".
+const INFORMAL_CONSONANTS: [char; 11] = ['m', 'm', 'd', 'z', 't', 't', 't', 'm', 'v', 'n', 'm'];
+
+fn informal_consonant(number: u64) -> char {
+    INFORMAL_CONSONANTS
+        .get(number as usize)
+        .copied()
+        .unwrap_or('m')
+}
+
+/// Reads the indicator after a Roman number: an ordinal indicator or the degree sign.
+fn roman_indicator_parser(indicator: &str) -> Option<Options> {
+    let ending = match indicator {
+        "º" | "°" => 'o',
+        "ª" => 'a',
+        _ => return None,
+    };
+
+    Some(gender_and_number(ending))
+}
+
+/// Reads the indicator after Arabic digits: a letter, an ordinal indicator, the degree sign, a superscript letter
+/// or the informal ending of the ordinal, like "2do" for "secondo" or "10mo" for "decimo".
+fn indicator_parser(indicator: &str, number: u64) -> Option<Options> {
+    let indicator = indicator
+        .strip_prefix(SUPERSCRIPT.0)
+        .and_then(|indicator| indicator.strip_suffix(SUPERSCRIPT.1))
+        .unwrap_or(indicator);
+    let indicator = indicator
+        .strip_prefix(informal_consonant(number))
+        .unwrap_or(indicator);
+
+    let mut letters = indicator.chars();
+    let ending = match (letters.next()?, letters.next()) {
+        ('º' | '°', None) => 'o',
+        ('ª', None) => 'a',
+        (ending @ ('o' | 'a' | 'i' | 'e'), None) => ending,
+        _ => return None,
+    };
+
+    Some(gender_and_number(ending))
+}
+
+/// Converts a number to its short ordinal form, with digits and an indicator
+///
+/// # Arguments
+///
+/// * `number` - The number to convert.
+/// * `options` - Optional settings for gender and plurality.
+/// * `indicator` - How the indicator is written.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{ordinal_indicator_converter, OrdinalIndicator, Options};
+///
+/// let result = ordinal_indicator_converter(1, None, OrdinalIndicator::Ordinal);
+/// assert_eq!(String::from("1º"), result);
+///
+/// let result = ordinal_indicator_converter(1, Some(Options::new(true, false)), OrdinalIndicator::Degree);
+/// assert_eq!(String::from("1ª"), result);
+///
+/// let result = ordinal_indicator_converter(3, Some(Options::new(true, true)), OrdinalIndicator::Html);
+/// assert_eq!(String::from("3<sup>e</sup>"), result);
+/// ```
+pub fn ordinal_indicator_converter(
+    number: u64,
+    options: Option<Options>,
+    indicator: OrdinalIndicator,
+) -> String {
+    let options = options.unwrap_or_default();
+    let ending = match (options.is_female(), options.is_plural()) {
+        (false, false) => 'o',
+        (true, false) => 'a',
+        (false, true) => 'i',
+        (true, true) => 'e',
+    };
+
+    match (indicator, ending) {
+        (OrdinalIndicator::Ordinal, 'o') => format!("{}º", number),
+        (OrdinalIndicator::Degree, 'o') => format!("{}°", number),
+        (OrdinalIndicator::Ordinal | OrdinalIndicator::Degree, 'a') => format!("{}ª", number),
+        (OrdinalIndicator::Html, _) => {
+            format!("{}{}{}{}", number, SUPERSCRIPT.0, ending, SUPERSCRIPT.1)
+        }
+        _ => format!("{}{}", number, ending),
+    }
+}
+
+/// Converts a short ordinal form to its number, with the gender and plurality of its indicator
+///
+/// # Arguments
+///
+/// * `text` - The short ordinal to convert: Arabic digits followed by "º", "°", "ª", "o", "a", "i", "e", the same
+///   letters in HTML superscript, or the informal ending of the ordinal, as in "2do", "8vo" or "10mo"; or an
+///   uppercase Roman number followed by "º", "°" or "ª".
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{ordinal_indicator_parser, Options};
+///
+/// let result = ordinal_indicator_parser("2ª");
+/// assert_eq!(Ok((2, Options::new(true, false))), result);
+///
+/// let result = ordinal_indicator_parser("XX°");
+/// assert_eq!(Ok((20, Options::new(false, false))), result);
+///
+/// let result = ordinal_indicator_parser("10mo");
+/// assert_eq!(Ok((10, Options::new(false, false))), result);
+///
+/// let result = ordinal_indicator_parser("3<sup>e</sup>");
+/// assert_eq!(Ok((3, Options::new(true, true))), result);
+///
+/// let result = ordinal_indicator_parser("Mi");
+/// assert_eq!(Err("invalid ordinal indicator"), result);
+/// ```
+pub fn ordinal_indicator_parser(text: &str) -> Result<(u64, Options), &'static str> {
+    let text = text.trim();
+    let is_roman = text.starts_with(['I', 'V', 'X', 'L', 'C', 'D', 'M']);
+    let number_end = text
+        .find(|letter: char| {
+            if is_roman {
+                !"IVXLCDM".contains(letter)
+            } else {
+                !letter.is_ascii_digit()
+            }
+        })
+        .ok_or("invalid ordinal indicator")?;
+    let (number, indicator) = text.split_at(number_end);
+
+    let (number, options) = if is_roman {
+        let number = arabic_converter(number).map_err(|_| "invalid ordinal indicator")? as u64;
+        (number, roman_indicator_parser(indicator))
+    } else if number.is_empty() {
+        return Err("invalid ordinal indicator");
+    } else {
+        let number = number.parse().map_err(|_| "invalid ordinal indicator")?;
+        (number, indicator_parser(indicator, number))
+    };
+    let options = options.ok_or("invalid ordinal indicator")?;
+
+    Ok((number, options))
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{ordinal_indicator_converter, ordinal_indicator_parser, OrdinalIndicator};
+    use crate::Options;
+
+    fn all_options() -> [Options; 4] {
+        [
+            Options::new(false, false),
+            Options::new(true, false),
+            Options::new(false, true),
+            Options::new(true, true),
+        ]
+    }
+
+    mod converter {
+        use super::*;
+
+        #[test]
+        fn test_ordinal() {
+            let indicators = all_options().map(|options| {
+                ordinal_indicator_converter(1, Some(options), OrdinalIndicator::Ordinal)
+            });
+            assert_eq!(indicators, ["1º", "1ª", "1i", "1e"]);
+        }
+
+        #[test]
+        fn test_degree() {
+            let indicators = all_options().map(|options| {
+                ordinal_indicator_converter(2, Some(options), OrdinalIndicator::Degree)
+            });
+            assert_eq!(indicators, ["2°", "2ª", "2i", "2e"]);
+        }
+
+        #[test]
+        fn test_letter() {
+            let indicators = all_options().map(|options| {
+                ordinal_indicator_converter(3, Some(options), OrdinalIndicator::Letter)
+            });
+            assert_eq!(indicators, ["3o", "3a", "3i", "3e"]);
+        }
+
+        #[test]
+        fn test_html() {
+            assert_eq!(
+                ordinal_indicator_converter(10, None, OrdinalIndicator::Html),
+                "10<sup>o</sup>"
+            );
+        }
+    }
+
+    mod parser {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let indicators = [
+                OrdinalIndicator::Ordinal,
+                OrdinalIndicator::Degree,
+                OrdinalIndicator::Letter,
+                OrdinalIndicator::Html,
+            ];
+
+            for indicator in indicators {
+                for options in all_options() {
+                    for number in [0, 1, 21, 1_000] {
+                        let text = ordinal_indicator_converter(number, Some(options), indicator);
+                        assert_eq!(
+                            ordinal_indicator_parser(&text),
+                            Ok((number, options)),
+                            "{}",
+                            text
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_informal() {
+            assert_eq!(
+                ordinal_indicator_parser("10mo"),
+                Ok((10, Options::new(false, false)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("2do"),
+                Ok((2, Options::new(false, false)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("3ze"),
+                Ok((3, Options::new(true, true)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("4ta"),
+                Ok((4, Options::new(true, false)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("8vi"),
+                Ok((8, Options::new(false, true)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("21mo"),
+                Ok((21, Options::new(false, false)))
+            );
+        }
+
+        #[test]
+        fn test_roman() {
+            assert_eq!(
+                ordinal_indicator_parser("XX°"),
+                Ok((20, Options::new(false, false)))
+            );
+            assert_eq!(
+                ordinal_indicator_parser("IIIª"),
+                Ok((3, Options::new(true, false)))
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid() {
+            let texts = [
+                "",
+                "1",
+                "º",
+                "1u",
+                "1ºº",
+                "1 º",
+                "IIIIº",
+                "Xmox",
+                "Xo",
+                "X<sup>o</sup>",
+                "2mo",
+                "4ma",
+                "11do",
+                "1<sup>o",
+                "99999999999999999999º",
+            ];
+
+            for text in texts {
+                assert_eq!(
+                    ordinal_indicator_parser(text),
+                    Err("invalid ordinal indicator"),
+                    "{}",
+                    text
+                );
+            }
+        }
+
+        #[test]
+        fn test_words_starting_with_roman_letters() {
+            for text in ["Io", "Mi", "Ma", "Me", "Di", "Ci", "Vi", "Le", "Li", "Ce"] {
+                assert_eq!(
+                    ordinal_indicator_parser(text),
+                    Err("invalid ordinal indicator"),
+                    "{}",
+                    text
+                );
+            }
+        }
+    }
+}
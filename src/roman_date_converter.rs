@@ -0,0 +1,377 @@
+use crate::{arabic_converter, roman_converter, LATIN_MONTHS_ABLATIVE, LATIN_MONTHS_ACCUSATIVE};
+
+const KALENDAE: [&str; 2] = ["Kalendis", "Kalendas"];
+const NONAE: [&str; 2] = ["Nonis", "Nonas"];
+const IDUS: [&str; 2] = ["Idibus", "Idus"];
+
+fn month_length(month: u8, leap_year: bool) -> u8 {
+    match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn nones(month: u8) -> u8 {
+    match month {
+        3 | 5 | 7 | 10 => 7,
+        _ => 5,
+    }
+}
+
+fn days_before(count: u8, reference: &str, month: u8) -> Result<String, &'static str> {
+    let month = LATIN_MONTHS_ACCUSATIVE[month as usize - 1];
+
+    if count == 2 {
+        return Ok(format!("pridie {} {}", reference, month));
+    }
+
+    Ok(format!(
+        "ante diem {} {} {}",
+        roman_converter(count as u16)?,
+        reference,
+        month
+    ))
+}
+
+/// Converts a day and a month to the ancient Roman date notation
+///
+/// # Arguments
+///
+/// * `day` - The day of the month, starting from 1.
+/// * `month` - The month, from 1 (Ianuarius) to 12 (December).
+/// * `leap_year` - If true, February has 29 days and the 25th is the doubled "ante diem bis VI Kalendas Martias".
+///
+/// Days are counted inclusively backwards from the next Kalendae (1st), Nonae (5th, or 7th in March, May, July and October)
+/// or Idus (13th, or 15th in March, May, July and October).
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::roman_date_converter;
+///
+/// let result = roman_date_converter(15, 3, false);
+/// assert_eq!(Ok(String::from("Idibus Martiis")), result);
+///
+/// let result = roman_date_converter(4, 1, false);
+/// assert_eq!(Ok(String::from("pridie Nonas Ianuarias")), result);
+///
+/// let result = roman_date_converter(27, 2, false);
+/// assert_eq!(Ok(String::from("ante diem III Kalendas Martias")), result);
+/// ```
+pub fn roman_date_converter(day: u8, month: u8, leap_year: bool) -> Result<String, &'static str> {
+    if !(1..=12).contains(&month) {
+        return Err("invalid month");
+    }
+    if day < 1 || day > month_length(month, leap_year) {
+        return Err("invalid day");
+    }
+
+    let nones = nones(month);
+    let ides = nones + 8;
+    let ablative = LATIN_MONTHS_ABLATIVE[month as usize - 1];
+
+    if day == 1 {
+        return Ok(format!("{} {}", KALENDAE[0], ablative));
+    }
+    if day == nones {
+        return Ok(format!("{} {}", NONAE[0], ablative));
+    }
+    if day == ides {
+        return Ok(format!("{} {}", IDUS[0], ablative));
+    }
+    if day < nones {
+        return days_before(nones - day + 1, NONAE[1], month);
+    }
+    if day < ides {
+        return days_before(ides - day + 1, IDUS[1], month);
+    }
+
+    let next_month = month % 12 + 1;
+    if month == 2 && leap_year {
+        if day == 25 {
+            return Ok(format!(
+                "ante diem bis VI {} {}",
+                KALENDAE[1],
+                LATIN_MONTHS_ACCUSATIVE[next_month as usize - 1]
+            ));
+        }
+        if day > 25 {
+            return days_before(28 - (day - 1) + 2, KALENDAE[1], next_month);
+        }
+    }
+
+    days_before(
+        month_length(month, false) - day + 2,
+        KALENDAE[1],
+        next_month,
+    )
+}
+
+fn get_month(word: &str, months: &[&str; 12]) -> Result<u8, &'static str> {
+    months
+        .iter()
+        .position(|month| month.to_lowercase() == word)
+        .map(|index| index as u8 + 1)
+        .ok_or("invalid roman date")
+}
+
+fn get_reference(word: &str, index: usize) -> Result<&'static str, &'static str> {
+    [KALENDAE, NONAE, IDUS]
+        .iter()
+        .map(|reference| reference[index])
+        .find(|reference| reference.to_lowercase() == word)
+        .ok_or("invalid roman date")
+}
+
+fn normalize(roman_date: &str) -> String {
+    roman_date
+        .to_lowercase()
+        .replace("a.d.", "ante diem")
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn day_before(count: u16, reference: &str, month: u8, leap_year: bool) -> (u8, u8) {
+    let count = count.min(31) as u8;
+
+    if reference == KALENDAE[1] {
+        let previous_month = (month + 10) % 12 + 1;
+        let length = month_length(previous_month, false);
+        let day = (length + 2).saturating_sub(count);
+
+        if previous_month == 2 && leap_year && day > 24 {
+            return (day + 1, previous_month);
+        }
+        return (day, previous_month);
+    }
+
+    let reference_day = if reference == NONAE[1] {
+        nones(month)
+    } else {
+        nones(month) + 8
+    };
+
+    ((reference_day + 1).saturating_sub(count), month)
+}
+
+/// Converts an ancient Roman date notation to a day and a month
+///
+/// # Arguments
+///
+/// * `roman_date` - The Roman date to convert. The abbreviation "a.d." is accepted for "ante diem".
+/// * `leap_year` - If true, February has 29 days and "ante diem bis VI Kalendas Martias" is accepted.
+///
+/// The result is a `(day, month)` tuple.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::arabic_date_converter;
+///
+/// let result = arabic_date_converter("Idibus Martiis", false);
+/// assert_eq!(Ok((15, 3)), result);
+///
+/// let result = arabic_date_converter("pridie Nonas Ianuarias", false);
+/// assert_eq!(Ok((4, 1)), result);
+///
+/// let result = arabic_date_converter("a.d. III Kalendas Martias", false);
+/// assert_eq!(Ok((27, 2)), result);
+/// ```
+pub fn arabic_date_converter(roman_date: &str, leap_year: bool) -> Result<(u8, u8), &'static str> {
+    let normalized_date = normalize(roman_date);
+    let words = normalized_date.split(' ').collect::<Vec<&str>>();
+
+    let (day, month) = match words[..] {
+        [reference, month] => {
+            let reference = get_reference(reference, 0)?;
+            let month = get_month(month, &LATIN_MONTHS_ABLATIVE)?;
+            let day = match reference {
+                "Kalendis" => 1,
+                "Nonis" => nones(month),
+                _ => nones(month) + 8,
+            };
+            (day, month)
+        }
+        ["pridie", reference, month] => {
+            let reference = get_reference(reference, 1)?;
+            let month = get_month(month, &LATIN_MONTHS_ACCUSATIVE)?;
+            day_before(2, reference, month, leap_year)
+        }
+        ["ante", "diem", "bis", "vi", "kalendas", "martias"] if leap_year => (25, 2),
+        ["ante", "diem", count, reference, month] => {
+            let count = arabic_converter(count.to_uppercase()).map_err(|_| "invalid roman date")?;
+            let reference = get_reference(reference, 1)?;
+            let month = get_month(month, &LATIN_MONTHS_ACCUSATIVE)?;
+            day_before(count, reference, month, leap_year)
+        }
+        _ => return Err("invalid roman date"),
+    };
+
+    match roman_date_converter(day, month, leap_year) {
+        Ok(date) if date.to_lowercase() == normalized_date => Ok((day, month)),
+        _ => Err("invalid roman date"),
+    }
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{arabic_date_converter, roman_date_converter};
+
+    mod to_roman {
+        use super::*;
+
+        #[test]
+        fn test_kalendae() {
+            assert_eq!(
+                roman_date_converter(1, 1, false),
+                Ok(String::from("Kalendis Ianuariis"))
+            );
+        }
+
+        #[test]
+        fn test_nonae() {
+            assert_eq!(
+                roman_date_converter(5, 4, false),
+                Ok(String::from("Nonis Aprilibus"))
+            );
+            assert_eq!(
+                roman_date_converter(7, 10, false),
+                Ok(String::from("Nonis Octobribus"))
+            );
+        }
+
+        #[test]
+        fn test_idus() {
+            assert_eq!(
+                roman_date_converter(15, 3, false),
+                Ok(String::from("Idibus Martiis"))
+            );
+            assert_eq!(
+                roman_date_converter(13, 12, false),
+                Ok(String::from("Idibus Decembribus"))
+            );
+        }
+
+        #[test]
+        fn test_pridie() {
+            assert_eq!(
+                roman_date_converter(6, 5, false),
+                Ok(String::from("pridie Nonas Maias"))
+            );
+            assert_eq!(
+                roman_date_converter(12, 8, false),
+                Ok(String::from("pridie Idus Augustas"))
+            );
+            assert_eq!(
+                roman_date_converter(31, 12, false),
+                Ok(String::from("pridie Kalendas Ianuarias"))
+            );
+        }
+
+        #[test]
+        fn test_ante_diem() {
+            assert_eq!(
+                roman_date_converter(2, 1, false),
+                Ok(String::from("ante diem IV Nonas Ianuarias"))
+            );
+            assert_eq!(
+                roman_date_converter(8, 7, false),
+                Ok(String::from("ante diem VIII Idus Iulias"))
+            );
+            assert_eq!(
+                roman_date_converter(14, 6, false),
+                Ok(String::from("ante diem XVIII Kalendas Iulias"))
+            );
+            assert_eq!(
+                roman_date_converter(27, 2, false),
+                Ok(String::from("ante diem III Kalendas Martias"))
+            );
+        }
+
+        #[test]
+        fn test_leap_year() {
+            assert_eq!(
+                roman_date_converter(24, 2, true),
+                Ok(String::from("ante diem VI Kalendas Martias"))
+            );
+            assert_eq!(
+                roman_date_converter(25, 2, true),
+                Ok(String::from("ante diem bis VI Kalendas Martias"))
+            );
+            assert_eq!(
+                roman_date_converter(29, 2, true),
+                Ok(String::from("pridie Kalendas Martias"))
+            );
+        }
+    }
+
+    mod to_arabic {
+        use super::*;
+
+        #[test]
+        fn test_all_days() {
+            for leap_year in [false, true] {
+                for month in 1..=12 {
+                    for day in 1..=31 {
+                        if let Ok(date) = roman_date_converter(day, month, leap_year) {
+                            assert_eq!(arabic_date_converter(&date, leap_year), Ok((day, month)));
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_abbreviation() {
+            assert_eq!(
+                arabic_date_converter("a.d. XVIII Kalendas Iulias", false),
+                Ok((14, 6))
+            );
+        }
+
+        #[test]
+        fn test_case_insensitive() {
+            assert_eq!(arabic_date_converter("idibus martiis", false), Ok((15, 3)));
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid_day() {
+            assert_eq!(roman_date_converter(29, 2, false), Err("invalid day"));
+            assert_eq!(roman_date_converter(31, 4, false), Err("invalid day"));
+        }
+
+        #[test]
+        fn test_invalid_month() {
+            assert_eq!(roman_date_converter(1, 13, false), Err("invalid month"));
+        }
+
+        #[test]
+        fn test_invalid_roman_date() {
+            assert_eq!(
+                arabic_date_converter("ante diem XX Nonas Martias", false),
+                Err("invalid roman date")
+            );
+            assert_eq!(
+                arabic_date_converter("ante diem II Idus Martias", false),
+                Err("invalid roman date")
+            );
+            assert_eq!(
+                arabic_date_converter("ante diem bis VI Kalendas Martias", false),
+                Err("invalid roman date")
+            );
+            assert_eq!(
+                arabic_date_converter("Idibus Martias", false),
+                Err("invalid roman date")
+            );
+        }
+    }
+}
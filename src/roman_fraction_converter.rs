@@ -0,0 +1,497 @@
+use crate::{arabic_converter, roman_converter};
+
+const SEMIS: char = 'S';
+const UNCIA: char = '·';
+const SEXTANS: char = '=';
+const UNCIA_SIGN: char = '𐆑';
+const SEXTANS_SIGN: char = '𐆐';
+const SEXTULA: char = 'Ɛ';
+const ZERO: &str = "N";
+
+/// How `roman_fraction_converter` writes the twelfths smaller than a semis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanFraction {
+    /// A dot for every uncia (5/12 is "·····").
+    Dots,
+    /// An equals sign for every sextans and a dot for the remaining uncia (5/12 is "==·").
+    Sextans,
+    /// The Unicode sextans (𐆐) and uncia (𐆑) signs (5/12 is "𐆐𐆐𐆑").
+    Unicode,
+}
+
+fn fraction_converter(twelfths: u8, notation: RomanFraction) -> String {
+    let mut fraction = String::new();
+    if twelfths >= 6 {
+        fraction.push(SEMIS);
+    }
+
+    let unciae = (twelfths % 6) as usize;
+    let (sextans, uncia) = match notation {
+        RomanFraction::Dots => return fraction + &UNCIA.to_string().repeat(unciae),
+        RomanFraction::Sextans => (SEXTANS, UNCIA),
+        RomanFraction::Unicode => (SEXTANS_SIGN, UNCIA_SIGN),
+    };
+
+    fraction.push_str(&sextans.to_string().repeat(unciae / 2));
+    if unciae % 2 == 1 {
+        fraction.push(uncia);
+    }
+
+    fraction
+}
+
+/// Converts an Arabic number with a duodecimal fraction to a Roman number
+///
+/// # Arguments
+///
+/// * `whole` - The whole part of the number, from 0 to 3999.
+/// * `twelfths` - The fraction, in twelfths (unciae), from 0 to 11.
+/// * `notation` - How the twelfths smaller than a semis (S, 6/12) are written.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_fraction_converter, RomanFraction};
+///
+/// let result = roman_fraction_converter(3, 8, RomanFraction::Dots);
+/// assert_eq!(Ok(String::from("IIIS··")), result);
+///
+/// let result = roman_fraction_converter(3, 9, RomanFraction::Sextans);
+/// assert_eq!(Ok(String::from("IIIS=·")), result);
+///
+/// let result = roman_fraction_converter(0, 5, RomanFraction::Unicode);
+/// assert_eq!(Ok(String::from("𐆐𐆐𐆑")), result);
+/// ```
+pub fn roman_fraction_converter(
+    whole: u16,
+    twelfths: u8,
+    notation: RomanFraction,
+) -> Result<String, &'static str> {
+    if twelfths > 11 {
+        return Err("invalid fraction");
+    }
+    if whole == 0 && twelfths == 0 {
+        return Err("lower than 1");
+    }
+
+    let mut result = String::new();
+    if whole > 0 {
+        result.push_str(&roman_converter(whole)?);
+    }
+    result.push_str(&fraction_converter(twelfths, notation));
+
+    Ok(result)
+}
+
+/// Converts a Roman number with a duodecimal fraction to an Arabic number
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert, in any `RomanFraction` notation.
+///
+/// The result is a `(whole, twelfths)` tuple. The fraction must be written as `roman_fraction_converter` would
+/// write it in one of the notations, so "IIIS··" and "IIIS=" are accepted while "IIIS·=" and "II······" are not.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::arabic_fraction_converter;
+///
+/// let result = arabic_fraction_converter("IIIS··");
+/// assert_eq!(Ok((3, 8)), result);
+///
+/// let result = arabic_fraction_converter("IIIS=·");
+/// assert_eq!(Ok((3, 9)), result);
+///
+/// let result = arabic_fraction_converter("S");
+/// assert_eq!(Ok((0, 6)), result);
+/// ```
+pub fn arabic_fraction_converter(roman_number: &str) -> Result<(u16, u8), &'static str> {
+    let index = roman_number
+        .find([SEMIS, UNCIA, SEXTANS, UNCIA_SIGN, SEXTANS_SIGN])
+        .unwrap_or(roman_number.len());
+    let (whole, fraction) = roman_number.split_at(index);
+
+    let whole = match whole {
+        "" if fraction.is_empty() => return Err("lower than 1"),
+        "" => 0,
+        whole => arabic_converter(whole)?,
+    };
+
+    let twelfths = fraction
+        .chars()
+        .map(|letter| match letter {
+            SEMIS => 6,
+            SEXTANS | SEXTANS_SIGN => 2,
+            _ => 1,
+        })
+        .sum::<usize>();
+    let twelfths = u8::try_from(twelfths)
+        .ok()
+        .filter(|&twelfths| twelfths <= 11)
+        .ok_or("invalid roman number")?;
+
+    let is_valid = [
+        RomanFraction::Dots,
+        RomanFraction::Sextans,
+        RomanFraction::Unicode,
+    ]
+    .iter()
+    .any(|&notation| fraction_converter(twelfths, notation) == fraction);
+    if !is_valid {
+        return Err("invalid roman number");
+    }
+
+    Ok((whole, twelfths))
+}
+
+/// Converts an Arabic number with a fraction in seventy-seconds to a Roman number, writing sextulae as "Ɛ"
+///
+/// # Arguments
+///
+/// * `whole` - The whole part of the number, from 0 to 3999.
+/// * `sextulae` - The fraction, in seventy-seconds (sextulae, a sixth of an uncia), from 0 to 71.
+/// * `notation` - How the twelfths smaller than a semis are written, as in `roman_fraction_converter`.
+///
+/// The twelfths are written as `roman_fraction_converter` does, followed by a "Ɛ" for every remaining sextula.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_sextula_converter, RomanFraction};
+///
+/// let result = roman_sextula_converter(3, 50, RomanFraction::Dots);
+/// assert_eq!(Ok(String::from("IIIS··ƐƐ")), result);
+///
+/// let result = roman_sextula_converter(0, 1, RomanFraction::Sextans);
+/// assert_eq!(Ok(String::from("Ɛ")), result);
+/// ```
+pub fn roman_sextula_converter(
+    whole: u16,
+    sextulae: u8,
+    notation: RomanFraction,
+) -> Result<String, &'static str> {
+    if sextulae > 71 {
+        return Err("invalid fraction");
+    }
+    if whole == 0 && sextulae == 0 {
+        return Err("lower than 1");
+    }
+
+    let mut result = String::new();
+    if whole > 0 {
+        result.push_str(&roman_converter(whole)?);
+    }
+    result.push_str(&fraction_converter(sextulae / 6, notation));
+    result.extend(std::iter::repeat(SEXTULA).take(sextulae as usize % 6));
+
+    Ok(result)
+}
+
+/// Converts a Roman number with a fraction written down to the sextula ("Ɛ") to an Arabic number
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert: a number read by `arabic_fraction_converter`, followed by up to
+///   five sextulae.
+///
+/// The result is a `(whole, sextulae)` tuple, with the fraction in seventy-seconds.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::arabic_sextula_converter;
+///
+/// let result = arabic_sextula_converter("IIIS··ƐƐ");
+/// assert_eq!(Ok((3, 50)), result);
+///
+/// let result = arabic_sextula_converter("IIIS=·");
+/// assert_eq!(Ok((3, 54)), result);
+/// ```
+pub fn arabic_sextula_converter(roman_number: &str) -> Result<(u16, u8), &'static str> {
+    let rest = roman_number.trim_end_matches(SEXTULA);
+    let sextulae = roman_number[rest.len()..].chars().count();
+    if sextulae > 5 {
+        return Err("invalid roman number");
+    }
+
+    let (whole, twelfths) = match rest {
+        "" if sextulae > 0 => (0, 0),
+        rest => arabic_fraction_converter(rest)?,
+    };
+
+    Ok((whole, twelfths * 6 + sextulae as u8))
+}
+
+/// Converts an Arabic number to a Roman number, writing zero as "N" (nulla)
+///
+/// # Arguments
+///
+/// * `number` - The number to convert, from 0 to 3999.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::roman_zero_converter;
+///
+/// let result = roman_zero_converter(0);
+/// assert_eq!(Ok(String::from("N")), result);
+///
+/// let result = roman_zero_converter(14);
+/// assert_eq!(Ok(String::from("XIV")), result);
+/// ```
+pub fn roman_zero_converter(number: u16) -> Result<String, &'static str> {
+    if number == 0 {
+        return Ok(ZERO.to_string());
+    }
+
+    roman_converter(number)
+}
+
+/// Converts a Roman number to an Arabic number, reading "N" (nulla) as zero
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::arabic_zero_converter;
+///
+/// let result = arabic_zero_converter("N");
+/// assert_eq!(Ok(0), result);
+///
+/// let result = arabic_zero_converter("XIV");
+/// assert_eq!(Ok(14), result);
+/// ```
+pub fn arabic_zero_converter(roman_number: &str) -> Result<u16, &'static str> {
+    if roman_number == ZERO {
+        return Ok(0);
+    }
+
+    arabic_converter(roman_number)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        arabic_fraction_converter, arabic_sextula_converter, arabic_zero_converter,
+        roman_fraction_converter, roman_sextula_converter, roman_zero_converter, RomanFraction,
+    };
+
+    mod to_roman {
+        use super::*;
+
+        #[test]
+        fn test_dots() {
+            assert_eq!(
+                roman_fraction_converter(3, 5, RomanFraction::Dots),
+                Ok(String::from("III·····"))
+            );
+            assert_eq!(
+                roman_fraction_converter(3, 8, RomanFraction::Dots),
+                Ok(String::from("IIIS··"))
+            );
+        }
+
+        #[test]
+        fn test_sextans() {
+            assert_eq!(
+                roman_fraction_converter(3, 5, RomanFraction::Sextans),
+                Ok(String::from("III==·"))
+            );
+            assert_eq!(
+                roman_fraction_converter(3, 8, RomanFraction::Sextans),
+                Ok(String::from("IIIS="))
+            );
+        }
+
+        #[test]
+        fn test_unicode() {
+            assert_eq!(
+                roman_fraction_converter(12, 11, RomanFraction::Unicode),
+                Ok(String::from("XIIS𐆐𐆐𐆑"))
+            );
+        }
+
+        #[test]
+        fn test_semis() {
+            assert_eq!(
+                roman_fraction_converter(1, 6, RomanFraction::Sextans),
+                Ok(String::from("IS"))
+            );
+        }
+
+        #[test]
+        fn test_whole() {
+            assert_eq!(
+                roman_fraction_converter(1_990, 0, RomanFraction::Dots),
+                Ok(String::from("MCMXC"))
+            );
+        }
+
+        #[test]
+        fn test_sextulae() {
+            assert_eq!(
+                roman_sextula_converter(3, 50, RomanFraction::Sextans),
+                Ok(String::from("IIIS=ƐƐ"))
+            );
+            assert_eq!(
+                roman_sextula_converter(0, 71, RomanFraction::Unicode),
+                Ok(String::from("S𐆐𐆐𐆑ƐƐƐƐƐ"))
+            );
+            assert_eq!(
+                roman_sextula_converter(2, 6, RomanFraction::Dots),
+                Ok(String::from("II·"))
+            );
+        }
+
+        #[test]
+        fn test_zero() {
+            assert_eq!(roman_zero_converter(0), Ok(String::from("N")));
+            assert_eq!(roman_zero_converter(3_999), Ok(String::from("MMMCMXCIX")));
+        }
+    }
+
+    mod to_arabic {
+        use super::*;
+
+        #[test]
+        fn test_all_fractions() {
+            for notation in [
+                RomanFraction::Dots,
+                RomanFraction::Sextans,
+                RomanFraction::Unicode,
+            ] {
+                for whole in [0, 1, 49, 3_999] {
+                    for twelfths in 0..=11 {
+                        if let Ok(roman_number) =
+                            roman_fraction_converter(whole, twelfths, notation)
+                        {
+                            assert_eq!(
+                                arabic_fraction_converter(&roman_number),
+                                Ok((whole, twelfths))
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_all_sextulae() {
+            for notation in [
+                RomanFraction::Dots,
+                RomanFraction::Sextans,
+                RomanFraction::Unicode,
+            ] {
+                for whole in [0, 1, 3_999] {
+                    for sextulae in 0..=71 {
+                        if let Ok(roman_number) = roman_sextula_converter(whole, sextulae, notation)
+                        {
+                            assert_eq!(
+                                arabic_sextula_converter(&roman_number),
+                                Ok((whole, sextulae))
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_unicode_whole() {
+            assert_eq!(arabic_fraction_converter("ⅫS·"), Ok((12, 7)));
+        }
+
+        #[test]
+        fn test_zero() {
+            assert_eq!(arabic_zero_converter("N"), Ok(0));
+            assert_eq!(arabic_zero_converter("MMXXIV"), Ok(2_024));
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid_fraction() {
+            assert_eq!(
+                roman_fraction_converter(1, 12, RomanFraction::Dots),
+                Err("invalid fraction")
+            );
+        }
+
+        #[test]
+        fn test_lower_than_1() {
+            assert_eq!(
+                roman_fraction_converter(0, 0, RomanFraction::Dots),
+                Err("lower than 1")
+            );
+            assert_eq!(arabic_fraction_converter(""), Err("lower than 1"));
+        }
+
+        #[test]
+        fn test_greater_than_3999() {
+            assert_eq!(
+                roman_fraction_converter(4_000, 1, RomanFraction::Dots),
+                Err("greater than 3999")
+            );
+        }
+
+        #[test]
+        fn test_non_canonical_fraction() {
+            assert_eq!(
+                arabic_fraction_converter("IIIS·="),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_fraction_converter("II······"),
+                Err("invalid roman number")
+            );
+            assert_eq!(arabic_fraction_converter("SS"), Err("invalid roman number"));
+            assert_eq!(
+                arabic_fraction_converter("S·I"),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_long_fraction() {
+            assert_eq!(
+                arabic_fraction_converter(&"S".repeat(50)),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_fraction_converter(&format!("I{}", "·".repeat(300))),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_invalid_sextulae() {
+            assert_eq!(
+                roman_sextula_converter(1, 72, RomanFraction::Dots),
+                Err("invalid fraction")
+            );
+            assert_eq!(
+                arabic_sextula_converter("IƐƐƐƐƐƐ"),
+                Err("invalid roman number")
+            );
+            assert_eq!(arabic_sextula_converter("IƐS"), Err("invalid roman number"));
+            assert_eq!(arabic_sextula_converter(""), Err("lower than 1"));
+        }
+
+        #[test]
+        fn test_zero_is_opt_in() {
+            assert_eq!(
+                crate::arabic_converter(String::from("N")),
+                Err("invalid roman number")
+            );
+            assert_eq!(arabic_fraction_converter("N"), Err("invalid roman number"));
+        }
+    }
+}
@@ -0,0 +1,480 @@
+use crate::roman_converter;
+
+const CANONICAL_SUBTRACTIONS: [(u16, u16); 6] = [
+    (1, 5),
+    (1, 10),
+    (10, 50),
+    (10, 100),
+    (100, 500),
+    (100, 1000),
+];
+
+/// How strictly `arabic_lenient_converter` reads a Roman number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanParsing {
+    /// Only the canonical form is accepted (XIV).
+    Strict,
+    /// Repeated symbols are also accepted (IIII, VIIII, XXXXX), but only the canonical subtractive pairs.
+    Additive,
+    /// Any symbol, or run of equal symbols, can also be subtracted from the following larger one (IIX, XIIX, IC).
+    LenientSubtractive,
+}
+
+/// A non-canonical pattern found by `arabic_lenient_converter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomanWarning {
+    position: usize,
+    pattern: String,
+    canonical: String,
+}
+
+impl RomanWarning {
+    /// Returns the position of the first character of the pattern.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the non-canonical pattern, as found in the input.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the canonical equivalent of the pattern.
+    pub fn canonical(&self) -> &str {
+        &self.canonical
+    }
+}
+
+/// The result of `arabic_lenient_converter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomanReading {
+    value: u16,
+    canonical: String,
+    warnings: Vec<RomanWarning>,
+}
+
+impl RomanReading {
+    /// Returns the value of the Roman number.
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+
+    /// Returns the canonical form of the Roman number.
+    pub fn canonical(&self) -> &str {
+        &self.canonical
+    }
+
+    /// Returns the non-canonical patterns found in the input.
+    pub fn warnings(&self) -> &[RomanWarning] {
+        &self.warnings
+    }
+
+    /// Returns `true` if the input was already in canonical form.
+    pub fn is_canonical(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// A run of equal symbols, optionally subtracted from the following larger symbol.
+struct Term {
+    start: usize,
+    end: usize,
+    value: u32,
+    is_canonical_subtraction: bool,
+}
+
+/// Consecutive terms with values of the same order of magnitude, read as a single digit.
+struct Group {
+    start: usize,
+    end: usize,
+    rank: u32,
+    value: u32,
+}
+
+fn get_arabic_number(letter: char) -> Result<u16, &'static str> {
+    match letter {
+        'I' => Ok(1),
+        'V' => Ok(5),
+        'X' => Ok(10),
+        'L' => Ok(50),
+        'C' => Ok(100),
+        'D' => Ok(500),
+        'M' => Ok(1000),
+        _ => Err("invalid roman number"),
+    }
+}
+
+/// Returns the value of a run of equal letters.
+fn get_run_value(value: u16, run: usize) -> Result<u32, &'static str> {
+    u32::try_from(run)
+        .ok()
+        .and_then(|run| (value as u32).checked_mul(run))
+        .ok_or("greater than 3999")
+}
+
+fn get_terms(values: &[u16]) -> Result<Vec<Term>, &'static str> {
+    let mut terms = Vec::new();
+    let mut i = 0;
+
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take_while(|&&value| value == values[i])
+            .count();
+        let next = values.get(i + run).copied().unwrap_or(0);
+
+        if next <= values[i] {
+            terms.push(Term {
+                start: i,
+                end: i + run,
+                value: get_run_value(values[i], run)?,
+                is_canonical_subtraction: true,
+            });
+            i += run;
+            continue;
+        }
+
+        let subtracted = get_run_value(values[i], run)?;
+        if subtracted >= next as u32 {
+            return Err("invalid roman number");
+        }
+
+        terms.push(Term {
+            start: i,
+            end: i + run + 1,
+            value: next as u32 - subtracted,
+            is_canonical_subtraction: run == 1
+                && CANONICAL_SUBTRACTIONS.contains(&(values[i], next)),
+        });
+        i += run + 1;
+    }
+
+    Ok(terms)
+}
+
+fn get_rank(value: u32) -> u32 {
+    value.ilog10()
+}
+
+/// Converts a Roman number to an Arabic number, accepting the non-canonical forms allowed by `parsing`
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert.
+/// * `parsing` - Which non-canonical forms are accepted.
+///
+/// The result contains the value, the canonical form and a warning for every non-canonical pattern,
+/// with its position in the input and its canonical equivalent.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{arabic_lenient_converter, RomanParsing};
+///
+/// let result = arabic_lenient_converter("IIII", RomanParsing::Additive).unwrap();
+/// assert_eq!(4, result.value());
+/// assert_eq!("IV", result.canonical());
+/// assert_eq!("IIII", result.warnings()[0].pattern());
+///
+/// let result = arabic_lenient_converter("MDCCCCX", RomanParsing::Additive).unwrap();
+/// assert_eq!(1910, result.value());
+/// assert_eq!(1, result.warnings()[0].position());
+/// assert_eq!("CM", result.warnings()[0].canonical());
+///
+/// let result = arabic_lenient_converter("IC", RomanParsing::LenientSubtractive).unwrap();
+/// assert_eq!(99, result.value());
+/// assert_eq!("XCIX", result.canonical());
+///
+/// let result = arabic_lenient_converter("IC", RomanParsing::Additive);
+/// assert_eq!(Err("invalid roman number"), result);
+/// ```
+pub fn arabic_lenient_converter(
+    roman_number: &str,
+    parsing: RomanParsing,
+) -> Result<RomanReading, &'static str> {
+    let letters = roman_number.chars().collect::<Vec<char>>();
+    let values = letters
+        .iter()
+        .map(|&letter| get_arabic_number(letter))
+        .collect::<Result<Vec<u16>, &'static str>>()?;
+
+    let terms = get_terms(&values)?;
+    if parsing == RomanParsing::Additive && terms.iter().any(|term| !term.is_canonical_subtraction)
+    {
+        return Err("invalid roman number");
+    }
+
+    let mut groups: Vec<Group> = Vec::new();
+    for term in terms {
+        let rank = get_rank(term.value);
+        match groups.last_mut() {
+            Some(group) if group.rank == rank => {
+                group.end = term.end;
+                group.value = group
+                    .value
+                    .checked_add(term.value)
+                    .ok_or("greater than 3999")?;
+            }
+            Some(group) if group.rank < rank => return Err("invalid roman number"),
+            _ => groups.push(Group {
+                start: term.start,
+                end: term.end,
+                rank,
+                value: term.value,
+            }),
+        }
+    }
+
+    let value = groups
+        .iter()
+        .try_fold(0_u32, |value, group| value.checked_add(group.value))
+        .and_then(|value| u16::try_from(value).ok())
+        .ok_or("greater than 3999")?;
+    let canonical = roman_converter(value)?;
+
+    let mut warnings = Vec::new();
+    let mut canonical_groups = String::new();
+    for group in groups {
+        let pattern = letters[group.start..group.end].iter().collect::<String>();
+        let canonical_group = roman_converter(group.value as u16)?;
+
+        canonical_groups.push_str(&canonical_group);
+        if pattern != canonical_group {
+            warnings.push(RomanWarning {
+                position: group.start,
+                pattern,
+                canonical: canonical_group,
+            });
+        }
+    }
+
+    if canonical_groups != canonical {
+        warnings = vec![RomanWarning {
+            position: 0,
+            pattern: roman_number.to_string(),
+            canonical: canonical.clone(),
+        }];
+    }
+
+    if parsing == RomanParsing::Strict && !warnings.is_empty() {
+        return Err("invalid roman number");
+    }
+
+    Ok(RomanReading {
+        value,
+        canonical,
+        warnings,
+    })
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{arabic_lenient_converter, RomanParsing};
+
+    fn reading(roman_number: &str, parsing: RomanParsing) -> (u16, String, Vec<String>) {
+        let result = arabic_lenient_converter(roman_number, parsing).unwrap();
+        let warnings = result
+            .warnings()
+            .iter()
+            .map(|warning| {
+                format!(
+                    "{}:{}->{}",
+                    warning.position(),
+                    warning.pattern(),
+                    warning.canonical()
+                )
+            })
+            .collect();
+
+        (result.value(), result.canonical().to_string(), warnings)
+    }
+
+    mod strict {
+        use super::*;
+
+        #[test]
+        fn test_canonical() {
+            for number in 1..=3_999 {
+                let roman_number = crate::roman_converter(number).unwrap();
+                let result = arabic_lenient_converter(&roman_number, RomanParsing::Strict).unwrap();
+                assert_eq!(result.value(), number);
+                assert!(result.is_canonical());
+            }
+        }
+
+        #[test]
+        fn test_non_canonical() {
+            assert_eq!(
+                arabic_lenient_converter("IIII", RomanParsing::Strict),
+                Err("invalid roman number")
+            );
+        }
+    }
+
+    mod additive {
+        use super::*;
+
+        #[test]
+        fn test_iiii() {
+            assert_eq!(
+                reading("IIII", RomanParsing::Additive),
+                (4, String::from("IV"), vec![String::from("0:IIII->IV")])
+            );
+        }
+
+        #[test]
+        fn test_viiii() {
+            assert_eq!(
+                reading("XVIIII", RomanParsing::Additive),
+                (19, String::from("XIX"), vec![String::from("1:VIIII->IX")])
+            );
+        }
+
+        #[test]
+        fn test_xxxxx() {
+            assert_eq!(
+                reading("XXXXXII", RomanParsing::Additive),
+                (52, String::from("LII"), vec![String::from("0:XXXXX->L")])
+            );
+        }
+
+        #[test]
+        fn test_several_patterns() {
+            assert_eq!(
+                reading("MDCCCCLXXXXIIII", RomanParsing::Additive),
+                (
+                    1_994,
+                    String::from("MCMXCIV"),
+                    vec![
+                        String::from("1:DCCCC->CM"),
+                        String::from("6:LXXXX->XC"),
+                        String::from("11:IIII->IV")
+                    ]
+                )
+            );
+        }
+
+        #[test]
+        fn test_canonical_subtraction() {
+            assert_eq!(
+                reading("XIV", RomanParsing::Additive),
+                (14, String::from("XIV"), vec![])
+            );
+        }
+
+        #[test]
+        fn test_non_canonical_subtraction() {
+            assert_eq!(
+                arabic_lenient_converter("XIIX", RomanParsing::Additive),
+                Err("invalid roman number")
+            );
+        }
+    }
+
+    mod lenient_subtractive {
+        use super::*;
+
+        #[test]
+        fn test_xiix() {
+            assert_eq!(
+                reading("XIIX", RomanParsing::LenientSubtractive),
+                (18, String::from("XVIII"), vec![String::from("1:IIX->VIII")])
+            );
+        }
+
+        #[test]
+        fn test_ic() {
+            assert_eq!(
+                reading("IC", RomanParsing::LenientSubtractive),
+                (99, String::from("XCIX"), vec![String::from("0:IC->XCIX")])
+            );
+        }
+
+        #[test]
+        fn test_mim() {
+            assert_eq!(
+                reading("MIM", RomanParsing::LenientSubtractive),
+                (
+                    1_999,
+                    String::from("MCMXCIX"),
+                    vec![String::from("1:IM->CMXCIX")]
+                )
+            );
+        }
+
+        #[test]
+        fn test_mixed_with_additive() {
+            assert_eq!(
+                reading("MDCCCCIIX", RomanParsing::LenientSubtractive),
+                (
+                    1_908,
+                    String::from("MCMVIII"),
+                    vec![String::from("1:DCCCC->CM"), String::from("6:IIX->VIII")]
+                )
+            );
+        }
+
+        #[test]
+        fn test_inconsistent_groups() {
+            assert_eq!(
+                reading("XCX", RomanParsing::LenientSubtractive),
+                (100, String::from("C"), vec![String::from("0:XCX->C")])
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_invalid_letter() {
+            assert_eq!(
+                arabic_lenient_converter("XIZ", RomanParsing::LenientSubtractive),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_increasing_groups() {
+            assert_eq!(
+                arabic_lenient_converter("IXX", RomanParsing::LenientSubtractive),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_subtraction_too_large() {
+            assert_eq!(
+                arabic_lenient_converter("VVX", RomanParsing::LenientSubtractive),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_long_runs() {
+            let roman_number = "M".repeat(5_000_000);
+            assert_eq!(
+                arabic_lenient_converter(&roman_number, RomanParsing::Additive),
+                Err("greater than 3999")
+            );
+        }
+
+        #[test]
+        fn test_greater_than_3999() {
+            assert_eq!(
+                arabic_lenient_converter("MMMMM", RomanParsing::Additive),
+                Err("greater than 3999")
+            );
+        }
+
+        #[test]
+        fn test_empty() {
+            assert_eq!(
+                arabic_lenient_converter("", RomanParsing::Additive),
+                Err("lower than 1")
+            );
+        }
+    }
+}
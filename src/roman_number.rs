@@ -0,0 +1,263 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+use crate::{arabic_converter, roman_converter, roman_style_converter, RomanStyle};
+
+/// A Roman number, from 1 to 3999.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Roman(u16);
+
+impl Roman {
+    /// Creates a new `Roman` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the number, from 1 to 3999.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use italian_numbers::Roman;
+    ///
+    /// let roman = Roman::new(14).unwrap();
+    /// assert_eq!("XIV", roman.to_string());
+    ///
+    /// let roman = Roman::new(4_000);
+    /// assert_eq!(Err("greater than 3999"), roman);
+    /// ```
+    pub fn new(value: u16) -> Result<Roman, &'static str> {
+        if value > 3999 {
+            return Err("greater than 3999");
+        }
+        if value < 1 {
+            return Err("lower than 1");
+        }
+
+        Ok(Roman(value))
+    }
+
+    /// Returns the value of the number.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number written in the given style.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The notation used to write the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use italian_numbers::{Roman, RomanStyle};
+    ///
+    /// let roman = Roman::new(12).unwrap();
+    /// assert_eq!(Ok(String::from("Ⅻ")), roman.to_style(RomanStyle::Unicode));
+    /// assert_eq!(Ok(String::from("XII")), roman.to_style(RomanStyle::Classic));
+    /// ```
+    pub fn to_style(&self, style: RomanStyle) -> Result<String, &'static str> {
+        roman_style_converter(self.0 as u32, style)
+    }
+}
+
+impl fmt::Display for Roman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let roman_number = roman_converter(self.0).map_err(|_| fmt::Error)?;
+        f.pad(&roman_number)
+    }
+}
+
+impl FromStr for Roman {
+    type Err = &'static str;
+
+    fn from_str(roman_number: &str) -> Result<Roman, &'static str> {
+        arabic_converter(roman_number).map(Roman)
+    }
+}
+
+impl Add for Roman {
+    type Output = Result<Roman, &'static str>;
+
+    fn add(self, other: Roman) -> Result<Roman, &'static str> {
+        Roman::new(self.0 + other.0)
+    }
+}
+
+impl Sub for Roman {
+    type Output = Result<Roman, &'static str>;
+
+    fn sub(self, other: Roman) -> Result<Roman, &'static str> {
+        match self.0.checked_sub(other.0) {
+            Some(value) => Roman::new(value),
+            None => Err("lower than 1"),
+        }
+    }
+}
+
+impl Mul for Roman {
+    type Output = Result<Roman, &'static str>;
+
+    fn mul(self, other: Roman) -> Result<Roman, &'static str> {
+        match self.0.checked_mul(other.0) {
+            Some(value) => Roman::new(value),
+            None => Err("greater than 3999"),
+        }
+    }
+}
+
+macro_rules! roman_try_from {
+    ($($integer:ty),*) => {
+        $(
+            impl TryFrom<$integer> for Roman {
+                type Error = &'static str;
+
+                fn try_from(value: $integer) -> Result<Roman, &'static str> {
+                    if value < 1 {
+                        return Err("lower than 1");
+                    }
+
+                    u16::try_from(value)
+                        .map_err(|_| "greater than 3999")
+                        .and_then(Roman::new)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! roman_into {
+    ($($integer:ty),*) => {
+        $(
+            impl From<Roman> for $integer {
+                fn from(roman: Roman) -> $integer {
+                    roman.0.into()
+                }
+            }
+        )*
+    };
+}
+
+roman_try_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+roman_into!(u16, u32, u64, u128, usize, i32, i64, i128);
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::Roman;
+    use crate::RomanStyle;
+
+    fn roman(value: u16) -> Roman {
+        Roman::new(value).unwrap()
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn test_classic() {
+            assert_eq!(roman(1_990).to_string(), "MCMXC");
+            assert_eq!(format!("{:>6}", roman(4)), "    IV");
+        }
+
+        #[test]
+        fn test_styles() {
+            assert_eq!(
+                roman(499).to_style(RomanStyle::Concise(4)),
+                Ok(String::from("ID"))
+            );
+            assert_eq!(
+                roman(3_999).to_style(RomanStyle::Unicode),
+                Ok(String::from("ⅯⅯⅯⅭⅯⅩⅭⅠⅩ"))
+            );
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_from_str() {
+            assert_eq!("MMXXIV".parse::<Roman>(), Ok(roman(2_024)));
+            assert_eq!("Ⅻ".parse::<Roman>(), Ok(roman(12)));
+        }
+
+        #[test]
+        fn test_round_trip() {
+            for value in 1..=3_999 {
+                assert_eq!(roman(value).to_string().parse::<Roman>(), Ok(roman(value)));
+            }
+        }
+    }
+
+    mod arithmetic {
+        use super::*;
+
+        #[test]
+        fn test_add() {
+            assert_eq!(roman(1_000) + roman(990), Ok(roman(1_990)));
+        }
+
+        #[test]
+        fn test_sub() {
+            assert_eq!(roman(14) - roman(5), Ok(roman(9)));
+        }
+
+        #[test]
+        fn test_mul() {
+            assert_eq!(roman(12) * roman(12), Ok(roman(144)));
+        }
+
+        #[test]
+        fn test_ordering() {
+            let mut romans = vec![roman(50), roman(4), roman(1_000)];
+            romans.sort();
+            assert_eq!(romans, vec![roman(4), roman(50), roman(1_000)]);
+        }
+    }
+
+    mod conversions {
+        use super::*;
+
+        #[test]
+        fn test_try_from() {
+            assert_eq!(Roman::try_from(14_u8), Ok(roman(14)));
+            assert_eq!(Roman::try_from(3_999_u64), Ok(roman(3_999)));
+            assert_eq!(Roman::try_from(7_i32), Ok(roman(7)));
+        }
+
+        #[test]
+        fn test_into() {
+            assert_eq!(u16::from(roman(14)), 14);
+            assert_eq!(u64::from(roman(3_999)), 3_999);
+            assert_eq!(i32::from(roman(7)), 7);
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_out_of_range() {
+            assert_eq!(Roman::new(0), Err("lower than 1"));
+            assert_eq!(Roman::new(4_000), Err("greater than 3999"));
+            assert_eq!(Roman::try_from(-1_i64), Err("lower than 1"));
+            assert_eq!(Roman::try_from(70_000_u32), Err("greater than 3999"));
+        }
+
+        #[test]
+        fn test_overflow() {
+            assert_eq!(roman(3_000) + roman(1_000), Err("greater than 3999"));
+            assert_eq!(roman(5) - roman(5), Err("lower than 1"));
+            assert_eq!(roman(5) - roman(6), Err("lower than 1"));
+            assert_eq!(roman(3_999) * roman(3_999), Err("greater than 3999"));
+        }
+
+        #[test]
+        fn test_invalid_roman_number() {
+            assert_eq!("IIII".parse::<Roman>(), Err("invalid roman number"));
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use crate::roman_scanner::{is_preceding_noun, is_proper_name};
+use crate::{
+    arabic_converter, italian_converter, ordinal_converter, roman_converter, Options,
+    ZERO_TEN_ORDINALS,
+};
+
+const PUNCTUATION: [char; 9] = [',', '.', ';', ':', '!', '?', '(', ')', '"'];
+/// Nouns that name things with a capital letter, like "Vitamina C" or "Serie A", rather than a ruler.
+const LETTER_NOUNS: [&str; 14] = [
+    "vitamina",
+    "serie",
+    "classe",
+    "tipo",
+    "gruppo",
+    "girone",
+    "lettera",
+    "categoria",
+    "zona",
+    "fascia",
+    "piano",
+    "scala",
+    "taglia",
+    "modello",
+];
+
+fn replace_words(
+    text: &str,
+    mut replace: impl FnMut(Option<&str>, &str) -> Option<Result<String, &'static str>>,
+) -> Result<(String, bool), &'static str> {
+    let mut result = String::new();
+    let mut found = false;
+    let mut previous = None;
+
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let start = piece.len() - piece.trim_start_matches(PUNCTUATION).len();
+        let word = piece.trim_end().trim_matches(PUNCTUATION);
+
+        let replacement = replace(previous, word).filter(|_| !word.is_empty());
+        if !piece.trim().is_empty() {
+            previous = Some(piece.trim());
+        }
+        match replacement {
+            Some(replacement) => {
+                result.push_str(&piece[..start]);
+                result.push_str(&replacement?);
+                result.push_str(&piece[start + word.len()..]);
+                found = true;
+            }
+            None => result.push_str(piece),
+        }
+    }
+
+    Ok((result, found))
+}
+
+/// Returns `true` if `word` is a Roman number. A single letter, which could be the article "I" or a letter like in
+/// "Vitamina C", must follow a noun such as "papa" or "cap.", or a name.
+fn is_roman_number(previous: Option<&str>, word: &str) -> bool {
+    if !word.chars().all(char::is_uppercase) || arabic_converter(word).is_err() {
+        return false;
+    }
+    if word.chars().count() > 1 {
+        return true;
+    }
+
+    previous.is_some_and(|previous| {
+        let name = previous.trim_matches(PUNCTUATION);
+        is_preceding_noun(previous)
+            || (is_proper_name(name) && !LETTER_NOUNS.contains(&name.to_lowercase().as_str()))
+    })
+}
+
+fn is_ordinal(word: &str) -> bool {
+    let word = word.to_lowercase();
+    let Some(stem) = word.strip_suffix(['o', 'a', 'i', 'e']) else {
+        return false;
+    };
+
+    stem.ends_with("esim")
+        || ZERO_TEN_ORDINALS[1..]
+            .iter()
+            .any(|ordinal| &ordinal[..ordinal.len() - 1] == stem)
+}
+
+/// Reads the Roman numbers in a name or title as Italian ordinals
+///
+/// # Arguments
+///
+/// * `text` - The text to convert, e.g. "Luigi XIV". Every uppercase word that is a valid Roman number is converted,
+///   while single letters are converted only after a name or a noun such as "papa" or "cap.", so "I Medici" and
+///   "Vitamina C" are kept.
+/// * `options` - Optional settings for gender and plurality of the ordinals.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_ordinal_converter, Options};
+///
+/// let result = roman_ordinal_converter("Luigi XIV", None);
+/// assert_eq!(Ok(String::from("Luigi quattordicesimo")), result);
+///
+/// let result = roman_ordinal_converter("Papa Giovanni XXIII", None);
+/// assert_eq!(Ok(String::from("Papa Giovanni ventitreesimo")), result);
+///
+/// let result = roman_ordinal_converter("XIX legislatura", Some(Options::new(true, false)));
+/// assert_eq!(Ok(String::from("diciannovesima legislatura")), result);
+/// ```
+pub fn roman_ordinal_converter(
+    text: &str,
+    options: Option<Options>,
+) -> Result<String, &'static str> {
+    let (result, found) = replace_words(text, |previous, word| {
+        if !is_roman_number(previous, word) {
+            return None;
+        }
+
+        Some(arabic_converter(word).and_then(|number| ordinal_converter(number as u64, options)))
+    })?;
+
+    if !found {
+        return Err("roman number not found");
+    }
+
+    Ok(result)
+}
+
+/// Writes the Italian ordinals in a name or title as Roman numbers
+///
+/// # Arguments
+///
+/// * `text` - The text to convert, e.g. "Luigi quattordicesimo". Every ordinal word, in any gender and plurality, is converted.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::ordinal_roman_converter;
+///
+/// let result = ordinal_roman_converter("Luigi quattordicesimo");
+/// assert_eq!(Ok(String::from("Luigi XIV")), result);
+///
+/// let result = ordinal_roman_converter("diciannovesima legislatura");
+/// assert_eq!(Ok(String::from("XIX legislatura")), result);
+/// ```
+pub fn ordinal_roman_converter(text: &str) -> Result<String, &'static str> {
+    let (result, found) = replace_words(text, |_, word| {
+        if !is_ordinal(word) {
+            return None;
+        }
+
+        let number = italian_converter(word.to_string()).ok()?;
+        let number = u16::try_from(number).map_err(|_| "greater than 3999");
+        Some(number.and_then(roman_converter))
+    })?;
+
+    if !found {
+        return Err("ordinal number not found");
+    }
+
+    Ok(result)
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{ordinal_roman_converter, roman_ordinal_converter};
+    use crate::Options;
+
+    mod to_ordinal {
+        use super::*;
+
+        #[test]
+        fn test_numeral() {
+            assert_eq!(
+                roman_ordinal_converter("XIV", None),
+                Ok(String::from("quattordicesimo"))
+            );
+        }
+
+        #[test]
+        fn test_regnal_name() {
+            assert_eq!(
+                roman_ordinal_converter("Enrico VIII d'Inghilterra", None),
+                Ok(String::from("Enrico ottavo d'Inghilterra"))
+            );
+        }
+
+        #[test]
+        fn test_feminine() {
+            assert_eq!(
+                roman_ordinal_converter("Elisabetta II", Some(Options::new(true, false))),
+                Ok(String::from("Elisabetta seconda"))
+            );
+        }
+
+        #[test]
+        fn test_plural() {
+            assert_eq!(
+                roman_ordinal_converter("i capitoli III", Some(Options::new(false, true))),
+                Ok(String::from("i capitoli terzi"))
+            );
+        }
+
+        #[test]
+        fn test_punctuation() {
+            assert_eq!(
+                roman_ordinal_converter("Papa Pio IX, detto Pio Nono.", None),
+                Ok(String::from("Papa Pio nono, detto Pio Nono."))
+            );
+        }
+
+        #[test]
+        fn test_several_numerals() {
+            assert_eq!(
+                roman_ordinal_converter("tra Luigi XIV e Luigi XVI", None),
+                Ok(String::from("tra Luigi quattordicesimo e Luigi sedicesimo"))
+            );
+        }
+
+        #[test]
+        fn test_lowercase_words_are_kept() {
+            assert_eq!(
+                roman_ordinal_converter("mix di Luigi XIV", None),
+                Ok(String::from("mix di Luigi quattordicesimo"))
+            );
+        }
+    }
+
+    mod single_letters {
+        use super::*;
+
+        #[test]
+        fn test_after_a_name() {
+            assert_eq!(
+                roman_ordinal_converter("Carlo V e Luigi I", None),
+                Ok(String::from("Carlo quinto e Luigi primo"))
+            );
+            assert_eq!(
+                roman_ordinal_converter("il cap. V", None),
+                Ok(String::from("il cap. quinto"))
+            );
+            assert_eq!(
+                roman_ordinal_converter("Papa Leone X.", None),
+                Ok(String::from("Papa Leone decimo."))
+            );
+        }
+
+        #[test]
+        fn test_article() {
+            assert_eq!(
+                roman_ordinal_converter("I Medici e Luigi XIV", None),
+                Ok(String::from("I Medici e Luigi quattordicesimo"))
+            );
+        }
+
+        #[test]
+        fn test_letters() {
+            assert_eq!(
+                roman_ordinal_converter("Vitamina C", None),
+                Err("roman number not found")
+            );
+            assert_eq!(
+                roman_ordinal_converter("la Serie C e la classe V", None),
+                Err("roman number not found")
+            );
+            assert_eq!(
+                roman_ordinal_converter("V", None),
+                Err("roman number not found")
+            );
+        }
+    }
+
+    mod to_roman {
+        use super::*;
+
+        #[test]
+        fn test_ordinal() {
+            assert_eq!(
+                ordinal_roman_converter("ventitreesimo"),
+                Ok(String::from("XXIII"))
+            );
+        }
+
+        #[test]
+        fn test_regnal_name() {
+            assert_eq!(
+                ordinal_roman_converter("Papa Giovanni ventitreesimo"),
+                Ok(String::from("Papa Giovanni XXIII"))
+            );
+        }
+
+        #[test]
+        fn test_small_ordinals() {
+            assert_eq!(
+                ordinal_roman_converter("Elisabetta seconda"),
+                Ok(String::from("Elisabetta II"))
+            );
+            assert_eq!(
+                ordinal_roman_converter("capitoli primi"),
+                Ok(String::from("capitoli I"))
+            );
+        }
+
+        #[test]
+        fn test_capitalized() {
+            assert_eq!(
+                ordinal_roman_converter("Diciannovesima legislatura"),
+                Ok(String::from("XIX legislatura"))
+            );
+        }
+
+        #[test]
+        fn test_similar_words_are_kept() {
+            assert_eq!(
+                ordinal_roman_converter("la primavera del terzo anno"),
+                Ok(String::from("la primavera del III anno"))
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_roman_number_not_found() {
+            assert_eq!(
+                roman_ordinal_converter("Luigi il Grande", None),
+                Err("roman number not found")
+            );
+        }
+
+        #[test]
+        fn test_ordinal_number_not_found() {
+            assert_eq!(
+                ordinal_roman_converter("Luigi il Grande"),
+                Err("ordinal number not found")
+            );
+        }
+
+        #[test]
+        fn test_greater_than_3999() {
+            assert_eq!(
+                ordinal_roman_converter("quattromillesimo"),
+                Err("greater than 3999")
+            );
+        }
+    }
+}
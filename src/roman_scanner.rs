@@ -0,0 +1,343 @@
+use crate::arabic_converter;
+
+const PUNCTUATION: [char; 15] = [
+    ',', '.', ';', ':', '!', '?', '(', ')', '"', '\'', '’', '«', '»', '[', ']',
+];
+const SEPARATORS: [char; 3] = ['-', '–', '—'];
+const PRECEDING_NOUNS: [&str; 26] = [
+    "secolo",
+    "sec.",
+    "capitolo",
+    "cap.",
+    "papa",
+    "re",
+    "regina",
+    "imperatore",
+    "libro",
+    "lib.",
+    "tomo",
+    "volume",
+    "vol.",
+    "parte",
+    "atto",
+    "scena",
+    "canto",
+    "articolo",
+    "art.",
+    "sezione",
+    "tavola",
+    "tav.",
+    "figura",
+    "fig.",
+    "appendice",
+    "legge",
+];
+const FOLLOWING_NOUNS: [&str; 10] = [
+    "secolo",
+    "secoli",
+    "sec.",
+    "legislatura",
+    "edizione",
+    "congresso",
+    "dinastia",
+    "olimpiade",
+    "millennio",
+    "municipio",
+];
+const AMBIGUOUS_WORDS: [&str; 11] = [
+    "MI", "DI", "CI", "VI", "LI", "MIX", "DC", "CD", "MD", "CV", "DIV",
+];
+
+const BASE_CONFIDENCE: f32 = 0.5;
+const CONTEXT_NOUN: f32 = 0.4;
+const PROPER_NAME: f32 = 0.3;
+const LONG_NUMBER: f32 = 0.1;
+const PARENTHESES: f32 = 0.1;
+const AMBIGUOUS_WORD: f32 = -0.4;
+const SINGLE_I: f32 = -0.2;
+const INITIAL: f32 = -0.3;
+const UPPERCASE_TEXT: f32 = -0.2;
+
+/// A Roman number found by `roman_scanner`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RomanMatch {
+    start: usize,
+    end: usize,
+    value: u16,
+    confidence: f32,
+}
+
+impl RomanMatch {
+    /// Returns the byte position where the Roman number starts.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the byte position where the Roman number ends.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the value of the Roman number.
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+
+    /// Returns how likely the word is a Roman number, from 0 to 1.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+/// A word of the text, with the punctuation around it.
+struct Token<'a> {
+    start: usize,
+    raw: &'a str,
+    word: &'a str,
+    after: &'a str,
+}
+
+fn get_tokens(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (i, letter) in text.char_indices().chain([(text.len(), ' ')]) {
+        if letter.is_whitespace() || SEPARATORS.contains(&letter) {
+            if let Some(start) = start.take() {
+                let raw = &text[start..i];
+                let leading = raw.len() - raw.trim_start_matches(PUNCTUATION).len();
+                let word = raw[leading..].trim_end_matches(PUNCTUATION);
+
+                tokens.push(Token {
+                    start: start + leading,
+                    raw,
+                    word,
+                    after: &raw[leading + word.len()..],
+                });
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    tokens
+}
+
+fn is_candidate(word: &str) -> bool {
+    !word.is_empty() && word.chars().all(char::is_uppercase)
+}
+
+fn is_context_noun(token: &Token, nouns: &[&str]) -> bool {
+    let word = token.raw.trim_start_matches(PUNCTUATION).to_lowercase();
+    let abbreviation =
+        word.trim_end_matches(|letter| letter != '.' && PUNCTUATION.contains(&letter));
+
+    nouns.contains(&abbreviation) || nouns.contains(&token.word.to_lowercase().as_str())
+}
+
+/// Returns `true` if `raw`, a word with its punctuation, is one of the nouns preceding a Roman number, like "cap.".
+pub(crate) fn is_preceding_noun(raw: &str) -> bool {
+    get_tokens(raw)
+        .first()
+        .is_some_and(|token| is_context_noun(token, &PRECEDING_NOUNS))
+}
+
+pub(crate) fn is_proper_name(word: &str) -> bool {
+    let mut letters = word.chars();
+
+    letters.next().is_some_and(char::is_uppercase)
+        && word.chars().count() > 1
+        && letters.all(char::is_lowercase)
+}
+
+fn is_uppercase_word(word: &str) -> bool {
+    word.chars().count() > 1
+        && word
+            .chars()
+            .all(|letter| letter.is_alphabetic() && letter.is_uppercase())
+        && arabic_converter(word).is_err()
+}
+
+fn get_confidence(tokens: &[Token], index: usize) -> f32 {
+    let token = &tokens[index];
+    let previous = index.checked_sub(1).map(|i| &tokens[i]);
+    let next = tokens.get(index + 1);
+    let mut confidence = BASE_CONFIDENCE;
+
+    if previous.is_some_and(|previous| is_context_noun(previous, &PRECEDING_NOUNS))
+        || next.is_some_and(|next| is_context_noun(next, &FOLLOWING_NOUNS))
+    {
+        confidence += CONTEXT_NOUN;
+    }
+    if previous.is_some_and(|previous| is_proper_name(previous.word)) {
+        confidence += PROPER_NAME;
+    }
+
+    if AMBIGUOUS_WORDS.contains(&token.word) {
+        confidence += AMBIGUOUS_WORD;
+    } else if token.word.chars().count() > 2 {
+        confidence += LONG_NUMBER;
+    }
+
+    if token.word == "I" {
+        confidence += SINGLE_I;
+    }
+    if token.word.chars().count() == 1
+        && token.after.starts_with('.')
+        && next.is_some_and(|next| is_proper_name(next.word))
+    {
+        confidence += INITIAL;
+    }
+    if token.raw.starts_with('(') && token.after.ends_with(')') {
+        confidence += PARENTHESES;
+    }
+    if previous.is_some_and(|previous| is_uppercase_word(previous.word))
+        || next.is_some_and(|next| is_uppercase_word(next.word))
+    {
+        confidence += UPPERCASE_TEXT;
+    }
+
+    confidence.clamp(0.0, 1.0)
+}
+
+/// Finds the Roman numbers in a text
+///
+/// # Arguments
+///
+/// * `text` - The text to scan, e.g. "il XV secolo".
+/// * `min_confidence` - The lowest confidence, from 0 to 1, of the returned numbers. 0.5 keeps the uppercase
+///   words that are valid Roman numbers, unless they look like ordinary words.
+///
+/// Every uppercase word accepted by `arabic_converter` is a candidate. Its confidence grows when it is preceded
+/// by a noun such as "secolo", "cap." or "papa", followed by "secolo" or "legislatura", or preceded by a proper name
+/// ("Enrico VIII"), and drops for words such as "MI", "DI", "CI" and "MIX", initials ("C. Rossi") and uppercase text.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::roman_scanner;
+///
+/// let result = roman_scanner("Nel XV secolo Enrico VIII lesse il cap. IV", 0.5);
+/// let values = result.iter().map(|found| found.value()).collect::<Vec<u16>>();
+/// assert_eq!(vec![15, 8, 4], values);
+/// assert_eq!(4, result[0].start());
+/// assert_eq!(6, result[0].end());
+///
+/// let result = roman_scanner("Il DJ fa un MIX", 0.5);
+/// assert!(result.is_empty());
+/// ```
+pub fn roman_scanner(text: &str, min_confidence: f32) -> Vec<RomanMatch> {
+    let tokens = get_tokens(text);
+
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| is_candidate(token.word))
+        .filter_map(|(index, token)| {
+            let value = arabic_converter(token.word).ok()?;
+            let confidence = get_confidence(&tokens, index);
+
+            (confidence >= min_confidence).then_some(RomanMatch {
+                start: token.start,
+                end: token.start + token.word.len(),
+                value,
+                confidence,
+            })
+        })
+        .collect()
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::roman_scanner;
+
+    fn values(text: &str) -> Vec<u16> {
+        roman_scanner(text, 0.5)
+            .iter()
+            .map(|found| found.value())
+            .collect()
+    }
+
+    mod context {
+        use super::*;
+
+        #[test]
+        fn test_secolo() {
+            let result = roman_scanner("il XV secolo", 0.5);
+            assert_eq!(result.len(), 1);
+            assert_eq!((result[0].start(), result[0].end()), (3, 5));
+            assert_eq!(result[0].value(), 15);
+            assert!(result[0].confidence() > 0.8);
+        }
+
+        #[test]
+        fn test_capitolo() {
+            assert_eq!(values("vedi cap. IV, pagina 3"), vec![4]);
+            assert_eq!(values("Capitolo XII."), vec![12]);
+        }
+
+        #[test]
+        fn test_proper_name() {
+            assert_eq!(values("Enrico VIII sposò sei mogli"), vec![8]);
+            assert_eq!(values("Papa Pio IX"), vec![9]);
+            assert_eq!(values("Papa Leone X"), vec![10]);
+            assert_eq!(values("Elisabetta I d'Inghilterra"), vec![1]);
+        }
+
+        #[test]
+        fn test_range() {
+            assert_eq!(values("tra il XV-XVI secolo"), vec![15, 16]);
+        }
+
+        #[test]
+        fn test_parentheses() {
+            let result = roman_scanner("la legge (XIV)", 0.0);
+            assert_eq!((result[0].start(), result[0].end()), (10, 13));
+        }
+
+        #[test]
+        fn test_unicode_span() {
+            let result = roman_scanner("Luigi Ⅻ", 0.5);
+            assert_eq!((result[0].start(), result[0].end()), (6, 9));
+            assert_eq!(result[0].value(), 12);
+        }
+    }
+
+    mod ordinary_words {
+        use super::*;
+
+        #[test]
+        fn test_ambiguous_words() {
+            assert!(values("Il DJ fa un MIX").is_empty());
+            assert!(values("CI vediamo, MI raccomando").is_empty());
+        }
+
+        #[test]
+        fn test_lowercase_words() {
+            assert!(values("mi dici di sì e ci vai").is_empty());
+        }
+
+        #[test]
+        fn test_uppercase_text() {
+            assert!(values("LUIGI DI MAIO").is_empty());
+        }
+
+        #[test]
+        fn test_initial() {
+            assert!(values("firmato da C. Rossi").is_empty());
+        }
+
+        #[test]
+        fn test_invalid_roman_number() {
+            assert!(values("il XIIII secolo").is_empty());
+        }
+
+        #[test]
+        fn test_min_confidence() {
+            assert_eq!(roman_scanner("Il DJ fa un MIX", 0.0).len(), 1);
+            assert!(roman_scanner("XV", 0.8).is_empty());
+        }
+    }
+}
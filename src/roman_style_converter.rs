@@ -0,0 +1,1121 @@
+use crate::{
+    arabic_converter, roman_converter, ROMAN_LETTERS, ROMAN_UNICODE_LETTERS, ROMAN_UNICODE_NUMERALS,
+};
+
+const COMBINING_OVERLINE: char = '\u{0305}';
+const REVERSED_C: char = 'Ↄ';
+const REVERSED_C_LOOKALIKES: [char; 2] = ['Ɔ', 'ↄ'];
+
+/// 400 in the apostrophus notation, where CIↃ would read 1000. Classic numbers never repeat C four times.
+const FOUR_HUNDRED: &str = "CCCC";
+const APOSTROPHUS_SYMBOLS: [&str; 11] = [
+    "I",
+    "V",
+    "X",
+    "L",
+    "C",
+    "IↃ",
+    "CIↃ",
+    "IↃↃ",
+    "CCIↃↃ",
+    "IↃↃↃ",
+    "CCCIↃↃↃ",
+];
+const APOSTROPHUS_UNICODE_SYMBOLS: [&str; 11] =
+    ["I", "V", "X", "L", "C", "D", "ↀ", "ↁ", "ↂ", "ↇ", "ↈ"];
+
+/// The notation used to write a Roman number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanStyle {
+    /// The standard notation, from 1 (I) to 3999 (MMMCMXCIX).
+    Classic,
+    /// Thousands from 4000 are written with a combining overline (U+0305), up to 3999999 (M̅M̅M̅C̅M̅X̅C̅I̅X̅CMXCIX).
+    Vinculum,
+    /// Like `Vinculum`, but every overlined letter is prefixed by an underscore (_I_V for 4000).
+    VinculumUnderscore,
+    /// Like `Vinculum`, but the overlined letters are wrapped in parentheses ((IV) for 4000).
+    VinculumParentheses,
+    /// Hundreds and thousands are written with the apostrophus (IↃ for 500, CIↃ for 1000, CCIↃↃ for 10000), up to 399999.
+    /// 400 is written CCCC, since CIↃ would read 1000.
+    Apostrophus,
+    /// Thousands are written with the single Unicode characters ↀ, ↁ, ↂ, ↇ and ↈ, up to 399999.
+    ApostrophusUnicode,
+    /// The Unicode Roman numeral characters (ⅯⅭⅯⅩⅭ), from 1 (Ⅰ) to 3999. Numbers up to 12 use a single character (Ⅻ).
+    Unicode,
+    /// The concise forms of the spreadsheet ROMAN function, from 0 (classic, CDXCIX for 499) to 4 (simplified, ID for 499),
+    /// up to 3999. They can be read back with `arabic_lenient_converter` and `RomanParsing::LenientSubtractive`.
+    Concise(u8),
+}
+
+/// The letter case of a Roman number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanCase {
+    /// Uppercase letters (XIV).
+    Upper,
+    /// Lowercase letters (xiv).
+    Lower,
+}
+
+fn classic_converter(number: u32) -> Result<String, &'static str> {
+    if number > 3_999 {
+        return Err("greater than 3999");
+    }
+
+    roman_converter(number as u16)
+}
+
+/// Follows the algorithm of the spreadsheet ROMAN function: for every digit 4 or 9, the subtracted symbol
+/// is replaced by a smaller one up to `form` times, as long as the difference still fits the number.
+fn concise_converter(number: u32, form: u8) -> Result<String, &'static str> {
+    const LETTERS: [char; 7] = ['M', 'D', 'C', 'L', 'X', 'V', 'I'];
+    const VALUES: [u32; 7] = [1_000, 500, 100, 50, 10, 5, 1];
+    const MAX_INDEX: usize = LETTERS.len() - 1;
+
+    if form > 4 {
+        return Err("invalid form");
+    }
+    classic_converter(number)?;
+
+    let mut rest = number;
+    let mut result = String::new();
+    for i in 0..=MAX_INDEX / 2 {
+        let mut index = 2 * i;
+        let digit = rest / VALUES[index];
+
+        if digit % 5 == 4 {
+            let larger_index = if digit == 4 { index - 1 } else { index - 2 };
+            let mut steps = 0;
+            while steps < form && index < MAX_INDEX {
+                steps += 1;
+                if VALUES[larger_index] - VALUES[index + 1] <= rest {
+                    index += 1;
+                } else {
+                    steps = form;
+                }
+            }
+
+            result.push(LETTERS[index]);
+            result.push(LETTERS[larger_index]);
+            rest = rest + VALUES[index] - VALUES[larger_index];
+        } else {
+            if digit > 4 {
+                result.push(LETTERS[index - 1]);
+            }
+            result.extend(std::iter::repeat(LETTERS[index]).take(digit as usize % 5));
+            rest %= VALUES[index];
+        }
+    }
+
+    Ok(result)
+}
+
+fn unicode_converter(number: u32) -> Result<String, &'static str> {
+    let roman_number = classic_converter(number)?;
+
+    if let Some((numeral, _)) = ROMAN_UNICODE_NUMERALS
+        .iter()
+        .take(12)
+        .find(|(_, value)| *value as u32 == number)
+    {
+        return Ok(numeral.to_string());
+    }
+
+    Ok(roman_number
+        .chars()
+        .map(|letter| {
+            let index = ROMAN_LETTERS
+                .iter()
+                .position(|&ascii| ascii.starts_with(letter))
+                .unwrap();
+            ROMAN_UNICODE_LETTERS[index]
+        })
+        .collect())
+}
+
+fn vinculum_converter(number: u32, style: RomanStyle) -> Result<String, &'static str> {
+    if number > 3_999_999 {
+        return Err("greater than 3999999");
+    }
+    if number < 4_000 {
+        return roman_converter(number as u16);
+    }
+
+    let thousands = roman_converter((number / 1_000) as u16)?;
+    let units = match number % 1_000 {
+        0 => String::new(),
+        rest => roman_converter(rest as u16)?,
+    };
+
+    let barred = match style {
+        RomanStyle::Vinculum => thousands
+            .chars()
+            .flat_map(|letter| [letter, COMBINING_OVERLINE])
+            .collect(),
+        RomanStyle::VinculumUnderscore => {
+            thousands.chars().flat_map(|letter| ['_', letter]).collect()
+        }
+        _ => format!("({})", thousands),
+    };
+
+    Ok(format!("{}{}", barred, units))
+}
+
+fn apostrophus_converter(number: u32, style: RomanStyle) -> Result<String, &'static str> {
+    if number > 399_999 {
+        return Err("greater than 399999");
+    }
+    if number < 1 {
+        return Err("lower than 1");
+    }
+
+    let symbols = match style {
+        RomanStyle::Apostrophus => APOSTROPHUS_SYMBOLS,
+        _ => APOSTROPHUS_UNICODE_SYMBOLS,
+    };
+
+    let mut result = String::new();
+    let mut power = 100_000;
+    for index in (0..6).rev() {
+        let digit = (number / power) % 10;
+        let one = symbols[2 * index];
+        let five = symbols.get(2 * index + 1).copied().unwrap_or_default();
+        let ten = symbols.get(2 * index + 2).copied().unwrap_or_default();
+
+        match digit {
+            4 if index == 2 && style == RomanStyle::Apostrophus => result.push_str(FOUR_HUNDRED),
+            1..=3 => result.push_str(&one.repeat(digit as usize)),
+            4 => result.push_str(&format!("{}{}", one, five)),
+            5..=8 => result.push_str(&format!("{}{}", five, one.repeat(digit as usize - 5))),
+            9 => result.push_str(&format!("{}{}", one, ten)),
+            _ => {}
+        }
+
+        power /= 10;
+    }
+
+    Ok(result)
+}
+
+/// Converts an Arabic number to a Roman number written in the given notation
+///
+/// # Arguments
+///
+/// * `number` - The number to convert.
+/// * `style` - The notation of the result.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_style_converter, RomanStyle};
+///
+/// let result = roman_style_converter(2317, RomanStyle::Classic);
+/// assert_eq!(Ok(String::from("MMCCCXVII")), result);
+///
+/// let result = roman_style_converter(5000, RomanStyle::Vinculum);
+/// assert_eq!(Ok(String::from("V\u{305}")), result);
+///
+/// let result = roman_style_converter(15_010, RomanStyle::VinculumUnderscore);
+/// assert_eq!(Ok(String::from("_X_VX")), result);
+///
+/// let result = roman_style_converter(1_004_000, RomanStyle::VinculumParentheses);
+/// assert_eq!(Ok(String::from("(MIV)")), result);
+///
+/// let result = roman_style_converter(1_500, RomanStyle::Apostrophus);
+/// assert_eq!(Ok(String::from("CIↃIↃ")), result);
+///
+/// let result = roman_style_converter(16_000, RomanStyle::ApostrophusUnicode);
+/// assert_eq!(Ok(String::from("ↂↁↀ")), result);
+///
+/// let result = roman_style_converter(12, RomanStyle::Unicode);
+/// assert_eq!(Ok(String::from("Ⅻ")), result);
+///
+/// let result = roman_style_converter(1990, RomanStyle::Unicode);
+/// assert_eq!(Ok(String::from("ⅯⅭⅯⅩⅭ")), result);
+///
+/// let result = roman_style_converter(499, RomanStyle::Concise(2));
+/// assert_eq!(Ok(String::from("XDIX")), result);
+/// ```
+pub fn roman_style_converter(number: u32, style: RomanStyle) -> Result<String, &'static str> {
+    match style {
+        RomanStyle::Classic => classic_converter(number),
+        RomanStyle::Concise(form) => concise_converter(number, form),
+        RomanStyle::Unicode => unicode_converter(number),
+        RomanStyle::Vinculum | RomanStyle::VinculumUnderscore | RomanStyle::VinculumParentheses => {
+            vinculum_converter(number, style)
+        }
+        RomanStyle::Apostrophus | RomanStyle::ApostrophusUnicode => {
+            apostrophus_converter(number, style)
+        }
+    }
+}
+
+/// Converts an Arabic number to a Roman number written in the given notation and letter case
+///
+/// # Arguments
+///
+/// * `number` - The number to convert.
+/// * `style` - The notation of the result.
+/// * `case` - The letter case of the result. Every notation can be lowercased, while the Unicode apostrophus
+///   characters (ↀ, ↁ, ↂ, ↇ, ↈ) have no lowercase form and are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_case_converter, RomanCase, RomanStyle};
+///
+/// let result = roman_case_converter(14, RomanStyle::Classic, RomanCase::Lower);
+/// assert_eq!(Ok(String::from("xiv")), result);
+///
+/// let result = roman_case_converter(12, RomanStyle::Unicode, RomanCase::Lower);
+/// assert_eq!(Ok(String::from("ⅻ")), result);
+///
+/// let result = roman_case_converter(1_500, RomanStyle::Apostrophus, RomanCase::Lower);
+/// assert_eq!(Ok(String::from("ciↄiↄ")), result);
+///
+/// let result = roman_case_converter(499, RomanStyle::Concise(4), RomanCase::Lower);
+/// assert_eq!(Ok(String::from("id")), result);
+/// ```
+pub fn roman_case_converter(
+    number: u32,
+    style: RomanStyle,
+    case: RomanCase,
+) -> Result<String, &'static str> {
+    let roman_number = roman_style_converter(number, style)?;
+
+    Ok(match case {
+        RomanCase::Upper => roman_number,
+        RomanCase::Lower => roman_number.to_lowercase(),
+    })
+}
+
+fn split_vinculum(roman_number: &str) -> Result<(String, String, RomanStyle), &'static str> {
+    let mut barred = String::new();
+    let mut plain = String::new();
+    let mut style = RomanStyle::Classic;
+
+    let mut set_style = |found: RomanStyle| {
+        if style != RomanStyle::Classic && style != found {
+            return Err("invalid roman number");
+        }
+        style = found;
+        Ok(())
+    };
+
+    let mut chars = roman_number.chars().peekable();
+    let mut in_parentheses = false;
+    while let Some(letter) = chars.next() {
+        match letter {
+            '(' if !in_parentheses && barred.is_empty() => {
+                set_style(RomanStyle::VinculumParentheses)?;
+                in_parentheses = true;
+            }
+            ')' if in_parentheses => in_parentheses = false,
+            '_' => {
+                set_style(RomanStyle::VinculumUnderscore)?;
+                match chars.next() {
+                    Some(next) if plain.is_empty() => barred.push(next),
+                    _ => return Err("invalid roman number"),
+                }
+            }
+            _ if chars.peek() == Some(&COMBINING_OVERLINE) => {
+                set_style(RomanStyle::Vinculum)?;
+                chars.next();
+                if !plain.is_empty() {
+                    return Err("invalid roman number");
+                }
+                barred.push(letter);
+            }
+            _ if in_parentheses => barred.push(letter),
+            _ => plain.push(letter),
+        }
+    }
+
+    if in_parentheses {
+        return Err("invalid roman number");
+    }
+
+    Ok((barred, plain, style))
+}
+
+fn is_apostrophus(letter: char) -> bool {
+    letter == REVERSED_C || ('ↀ'..='ↈ').contains(&letter) || REVERSED_C_LOOKALIKES.contains(&letter)
+}
+
+/// Returns `true` for numbers in the apostrophus notation: with an apostrophus symbol, or with 400 written CCCC
+/// among plain letters.
+fn is_apostrophus_number(roman_number: &str) -> bool {
+    let is_plain = || {
+        roman_number
+            .chars()
+            .all(|letter| ROMAN_LETTERS.iter().any(|ascii| ascii.starts_with(letter)))
+    };
+
+    roman_number.chars().any(is_apostrophus) || (roman_number.contains(FOUR_HUNDRED) && is_plain())
+}
+
+fn get_apostrophus_value(letter: char) -> Result<u32, &'static str> {
+    match letter {
+        'I' => Ok(1),
+        'V' => Ok(5),
+        'X' => Ok(10),
+        'L' => Ok(50),
+        'C' => Ok(100),
+        'D' => Ok(500),
+        'M' | 'ↀ' => Ok(1_000),
+        'ↁ' => Ok(5_000),
+        'ↂ' => Ok(10_000),
+        'ↇ' => Ok(50_000),
+        'ↈ' => Ok(100_000),
+        _ => Err("invalid roman number"),
+    }
+}
+
+/// Splits an apostrophus number in the values of its symbols, reading every C^n I Ↄ^n group as a single symbol.
+fn apostrophus_values(roman_number: &str, strict: bool) -> Result<Vec<u32>, &'static str> {
+    let letters = roman_number
+        .chars()
+        .map(|letter| match letter {
+            _ if !strict && REVERSED_C_LOOKALIKES.contains(&letter) => REVERSED_C,
+            _ => letter,
+        })
+        .collect::<Vec<char>>();
+
+    let count_from = |start: usize, letter: char| {
+        letters[start..]
+            .iter()
+            .take_while(|&&current| current == letter)
+            .count()
+    };
+
+    let mut values = Vec::new();
+    let mut i = 0;
+    while i < letters.len() {
+        let hundreds = count_from(i, 'C');
+        let has_apostrophus = letters.get(i + hundreds) == Some(&'I')
+            && letters.get(i + hundreds + 1) == Some(&REVERSED_C);
+
+        if !has_apostrophus {
+            if letters[i] == REVERSED_C {
+                return Err("invalid roman number");
+            }
+            values.push(get_apostrophus_value(letters[i])?);
+            i += 1;
+            continue;
+        }
+
+        let apostrophi = count_from(i + hundreds + 1, REVERSED_C);
+        let power = 10_u32
+            .checked_pow(apostrophi as u32 + 2)
+            .ok_or("invalid roman number")?;
+        if hundreds >= apostrophi {
+            values.extend(std::iter::repeat(100).take(hundreds - apostrophi));
+            values.push(power);
+        } else {
+            values.extend(std::iter::repeat(100).take(hundreds));
+            values.push(power / 2);
+        }
+        i += hundreds + 1 + apostrophi;
+    }
+
+    Ok(values)
+}
+
+fn apostrophus_parser(roman_number: &str, strict: bool) -> Result<u32, &'static str> {
+    let values = apostrophus_values(roman_number, strict)?;
+
+    let mut number: i64 = 0;
+    for (i, &value) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| next > value) {
+            number -= value as i64;
+        } else {
+            number += value as i64;
+        }
+    }
+
+    let number = u32::try_from(number).map_err(|_| "invalid roman number")?;
+    let canonical = if roman_number.contains(REVERSED_C) || roman_number.contains(FOUR_HUNDRED) {
+        apostrophus_converter(number, RomanStyle::Apostrophus)?
+    } else {
+        apostrophus_converter(number, RomanStyle::ApostrophusUnicode)?
+    };
+
+    let is_valid = if strict {
+        canonical == roman_number
+    } else {
+        apostrophus_values(&canonical, true)? == values
+    };
+    if !is_valid {
+        return Err("invalid roman number");
+    }
+
+    Ok(number)
+}
+
+fn is_unicode(letter: char) -> bool {
+    ROMAN_UNICODE_NUMERALS
+        .iter()
+        .any(|(numeral, _)| *numeral == letter)
+}
+
+fn unicode_parser(roman_number: &str, strict: bool) -> Result<u32, &'static str> {
+    let number = arabic_converter(roman_number)? as u32;
+
+    if strict && unicode_converter(number)? != roman_number {
+        return Err("invalid roman number");
+    }
+
+    Ok(number)
+}
+
+fn roman_case(roman_number: &str) -> Result<RomanCase, &'static str> {
+    let letters = || {
+        roman_number
+            .chars()
+            .filter(|&letter| !is_apostrophus(letter))
+    };
+    let has_lowercase = letters().any(char::is_lowercase);
+    let has_uppercase = letters().any(char::is_uppercase);
+
+    match (has_lowercase, has_uppercase) {
+        (true, true) => Err("invalid roman number"),
+        (true, false) => Ok(RomanCase::Lower),
+        _ => Ok(RomanCase::Upper),
+    }
+}
+
+/// Converts a Roman number written in any supported notation to an Arabic number
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert.
+/// * `strict` - If true, apostrophus numbers must be written exactly as `roman_style_converter` would write them.
+///   Otherwise the apostrophus forms, their Unicode characters and the letters D and M can be mixed,
+///   and Ɔ or ↄ are accepted in place of Ↄ. Unicode Roman numeral characters can be mixed with ASCII letters
+///   and used to compose numbers (ⅩⅡ for 12).
+///
+/// Lowercase numbers, as written by `roman_case_converter`, are read like their uppercase form, while numbers mixing
+/// both cases are rejected.
+///
+/// The notation is detected from the input: plain letters are read as `RomanStyle::Classic`, or as
+/// `RomanStyle::Apostrophus` when they write 400 as CCCC, while combining overlines, underscores and parentheses
+/// are read as the corresponding vinculum notation. Vinculum notations cannot be mixed.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::arabic_style_converter;
+///
+/// let result = arabic_style_converter("MCMXC", true);
+/// assert_eq!(Ok(1990), result);
+///
+/// let result = arabic_style_converter("V\u{305}", true);
+/// assert_eq!(Ok(5000), result);
+///
+/// let result = arabic_style_converter("_X_VX", true);
+/// assert_eq!(Ok(15_010), result);
+///
+/// let result = arabic_style_converter("(MIV)", true);
+/// assert_eq!(Ok(1_004_000), result);
+///
+/// let result = arabic_style_converter("CCIↃↃCIↃ", true);
+/// assert_eq!(Ok(11_000), result);
+///
+/// let result = arabic_style_converter("ↂCIƆ", false);
+/// assert_eq!(Ok(11_000), result);
+///
+/// let result = arabic_style_converter("Ⅻ", true);
+/// assert_eq!(Ok(12), result);
+///
+/// let result = arabic_style_converter("xiv", true);
+/// assert_eq!(Ok(14), result);
+/// ```
+pub fn arabic_style_converter(roman_number: &str, strict: bool) -> Result<u32, &'static str> {
+    match roman_case(roman_number)? {
+        RomanCase::Upper => style_parser(roman_number, strict),
+        RomanCase::Lower => {
+            let uppercase = roman_number.to_uppercase();
+            // Lowercase letters without an uppercase form, like ª and º, are not Roman letters.
+            if uppercase == roman_number {
+                return Err("invalid roman number");
+            }
+            style_parser(&uppercase, strict)
+        }
+    }
+}
+
+fn style_parser(roman_number: &str, strict: bool) -> Result<u32, &'static str> {
+    if is_apostrophus_number(roman_number) {
+        return apostrophus_parser(roman_number, strict);
+    }
+    if roman_number.chars().any(is_unicode) {
+        return unicode_parser(roman_number, strict);
+    }
+
+    let (barred, plain, style) = split_vinculum(roman_number)?;
+
+    let mut number = 0;
+    if !barred.is_empty() {
+        number += arabic_converter(barred)? as u32 * 1_000;
+    }
+    if !plain.is_empty() {
+        number += arabic_converter(plain)? as u32;
+    }
+
+    if roman_style_converter(number, style)? != roman_number {
+        return Err("invalid roman number");
+    }
+
+    Ok(number)
+}
+
+/// Converts a Roman number in either letter case to an Arabic number, reporting the case found
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert, in uppercase or lowercase letters.
+///
+/// Numbers mixing both cases are rejected. Unicode Roman numeral characters are accepted as in `arabic_converter`.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{arabic_case_converter, RomanCase};
+///
+/// let result = arabic_case_converter("xiv");
+/// assert_eq!(Ok((14, RomanCase::Lower)), result);
+///
+/// let result = arabic_case_converter("XIV");
+/// assert_eq!(Ok((14, RomanCase::Upper)), result);
+///
+/// let result = arabic_case_converter("ⅻ");
+/// assert_eq!(Ok((12, RomanCase::Lower)), result);
+/// ```
+pub fn arabic_case_converter(roman_number: &str) -> Result<(u16, RomanCase), &'static str> {
+    let case = roman_case(roman_number)?;
+    let number = arabic_converter(roman_number.to_uppercase())?;
+
+    Ok((number, case))
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        arabic_case_converter, arabic_style_converter, roman_case_converter, roman_style_converter,
+        RomanCase, RomanStyle,
+    };
+
+    mod classic {
+        use super::*;
+
+        #[test]
+        fn test_3_999() {
+            assert_eq!(
+                roman_style_converter(3_999, RomanStyle::Classic),
+                Ok(String::from("MMMCMXCIX"))
+            );
+            assert_eq!(arabic_style_converter("MMMCMXCIX", true), Ok(3_999));
+        }
+
+        #[test]
+        fn test_small_numbers_with_vinculum() {
+            assert_eq!(
+                roman_style_converter(3_999, RomanStyle::Vinculum),
+                Ok(String::from("MMMCMXCIX"))
+            );
+        }
+    }
+
+    mod vinculum {
+        use super::*;
+
+        #[test]
+        fn test_4_000() {
+            assert_eq!(
+                roman_style_converter(4_000, RomanStyle::Vinculum),
+                Ok(String::from("I\u{305}V\u{305}"))
+            );
+            assert_eq!(arabic_style_converter("I\u{305}V\u{305}", true), Ok(4_000));
+        }
+
+        #[test]
+        fn test_12_345() {
+            assert_eq!(
+                roman_style_converter(12_345, RomanStyle::Vinculum),
+                Ok(String::from("X\u{305}I\u{305}I\u{305}CCCXLV"))
+            );
+            assert_eq!(
+                arabic_style_converter("X\u{305}I\u{305}I\u{305}CCCXLV", true),
+                Ok(12_345)
+            );
+        }
+
+        #[test]
+        fn test_1_000_000() {
+            assert_eq!(
+                roman_style_converter(1_000_000, RomanStyle::Vinculum),
+                Ok(String::from("M\u{305}"))
+            );
+            assert_eq!(arabic_style_converter("M\u{305}", true), Ok(1_000_000));
+        }
+
+        #[test]
+        fn test_3_999_999() {
+            assert_eq!(
+                roman_style_converter(3_999_999, RomanStyle::Vinculum),
+                Ok(String::from(
+                    "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"
+                ))
+            );
+        }
+
+        #[test]
+        fn test_round_trip() {
+            for number in (4_000..3_999_999).step_by(997) {
+                for style in [
+                    RomanStyle::Vinculum,
+                    RomanStyle::VinculumUnderscore,
+                    RomanStyle::VinculumParentheses,
+                ] {
+                    let roman_number = roman_style_converter(number, style).unwrap();
+                    assert_eq!(arabic_style_converter(&roman_number, true), Ok(number));
+                }
+            }
+        }
+    }
+
+    mod ascii {
+        use super::*;
+
+        #[test]
+        fn test_underscore() {
+            assert_eq!(
+                roman_style_converter(5_000, RomanStyle::VinculumUnderscore),
+                Ok(String::from("_V"))
+            );
+            assert_eq!(arabic_style_converter("_L_X_VDCCC", true), Ok(65_800));
+        }
+
+        #[test]
+        fn test_parentheses() {
+            assert_eq!(
+                roman_style_converter(5_000, RomanStyle::VinculumParentheses),
+                Ok(String::from("(V)"))
+            );
+            assert_eq!(arabic_style_converter("(LXV)DCCC", true), Ok(65_800));
+        }
+    }
+
+    mod apostrophus {
+        use super::*;
+
+        #[test]
+        fn test_500() {
+            assert_eq!(
+                roman_style_converter(500, RomanStyle::Apostrophus),
+                Ok(String::from("IↃ"))
+            );
+            assert_eq!(
+                roman_style_converter(500, RomanStyle::ApostrophusUnicode),
+                Ok(String::from("D"))
+            );
+        }
+
+        #[test]
+        fn test_1_000() {
+            assert_eq!(
+                roman_style_converter(1_000, RomanStyle::Apostrophus),
+                Ok(String::from("CIↃ"))
+            );
+            assert_eq!(
+                roman_style_converter(1_000, RomanStyle::ApostrophusUnicode),
+                Ok(String::from("ↀ"))
+            );
+        }
+
+        #[test]
+        fn test_10_000() {
+            assert_eq!(
+                roman_style_converter(10_000, RomanStyle::Apostrophus),
+                Ok(String::from("CCIↃↃ"))
+            );
+            assert_eq!(arabic_style_converter("CCIↃↃ", true), Ok(10_000));
+            assert_eq!(arabic_style_converter("ↂ", true), Ok(10_000));
+        }
+
+        #[test]
+        fn test_400() {
+            assert_eq!(
+                roman_style_converter(400, RomanStyle::Apostrophus),
+                Ok(String::from("CCCC"))
+            );
+            assert_eq!(arabic_style_converter("CCCC", true), Ok(400));
+            assert_eq!(arabic_style_converter("CCCCXCIX", true), Ok(499));
+            assert_eq!(arabic_style_converter("CIↃCCCC", true), Ok(1_400));
+            assert_eq!(arabic_style_converter("ↀCCCC", false), Ok(1_400));
+            assert_eq!(
+                arabic_style_converter("CCCCC", true),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_1_990() {
+            assert_eq!(
+                roman_style_converter(1_990, RomanStyle::Apostrophus),
+                Ok(String::from("CIↃCCIↃXC"))
+            );
+            assert_eq!(arabic_style_converter("CIↃCCIↃXC", true), Ok(1_990));
+        }
+
+        #[test]
+        fn test_399_999() {
+            assert_eq!(
+                roman_style_converter(399_999, RomanStyle::ApostrophusUnicode),
+                Ok(String::from("ↈↈↈↂↈↀↂCↀXCIX"))
+            );
+        }
+
+        #[test]
+        fn test_round_trip() {
+            for number in 1..=399_999 {
+                for style in [RomanStyle::Apostrophus, RomanStyle::ApostrophusUnicode] {
+                    let roman_number = roman_style_converter(number, style).unwrap();
+                    assert_eq!(arabic_style_converter(&roman_number, true), Ok(number));
+                }
+            }
+        }
+
+        #[test]
+        fn test_lenient_round_trip() {
+            for number in (1..399_999).step_by(331) {
+                for style in [RomanStyle::Apostrophus, RomanStyle::ApostrophusUnicode] {
+                    let roman_number = roman_style_converter(number, style).unwrap();
+                    assert_eq!(arabic_style_converter(&roman_number, false), Ok(number));
+                }
+            }
+        }
+
+        #[test]
+        fn test_lenient() {
+            assert_eq!(arabic_style_converter("ↀIↃ", false), Ok(1_500));
+            assert_eq!(arabic_style_converter("CIƆMD", false), Ok(2_500));
+            assert_eq!(arabic_style_converter("CCIↄↄ", false), Ok(10_000));
+        }
+
+        #[test]
+        fn test_too_many_apostrophi() {
+            let roman_number = format!("I{}", "Ↄ".repeat(10));
+            assert_eq!(
+                arabic_style_converter(&roman_number, false),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_strict() {
+            assert_eq!(
+                arabic_style_converter("ↀIↃ", true),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("CCIƆƆ", true),
+                Err("invalid roman number")
+            );
+        }
+    }
+
+    mod unicode {
+        use super::*;
+
+        #[test]
+        fn test_single_characters() {
+            let expected = ["Ⅰ", "Ⅱ", "Ⅲ", "Ⅳ", "Ⅴ", "Ⅵ", "Ⅶ", "Ⅷ", "Ⅸ", "Ⅹ", "Ⅺ", "Ⅻ"];
+            for (number, numeral) in (1..=12).zip(expected) {
+                assert_eq!(
+                    roman_style_converter(number, RomanStyle::Unicode),
+                    Ok(String::from(numeral))
+                );
+                assert_eq!(arabic_style_converter(numeral, true), Ok(number));
+            }
+        }
+
+        #[test]
+        fn test_13() {
+            assert_eq!(
+                roman_style_converter(13, RomanStyle::Unicode),
+                Ok(String::from("ⅩⅠⅠⅠ"))
+            );
+        }
+
+        #[test]
+        fn test_3_999() {
+            assert_eq!(
+                roman_style_converter(3_999, RomanStyle::Unicode),
+                Ok(String::from("ⅯⅯⅯⅭⅯⅩⅭⅠⅩ"))
+            );
+            assert_eq!(arabic_style_converter("ⅯⅯⅯⅭⅯⅩⅭⅠⅩ", true), Ok(3_999));
+        }
+
+        #[test]
+        fn test_lenient() {
+            assert_eq!(arabic_style_converter("ⅩⅡ", false), Ok(12));
+            assert_eq!(arabic_style_converter("ⅯⅭⅯⅩⅭ", false), Ok(1_990));
+            assert_eq!(arabic_style_converter("MCMⅩⅭ", false), Ok(1_990));
+        }
+
+        #[test]
+        fn test_strict() {
+            assert_eq!(
+                arabic_style_converter("ⅩⅡ", true),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("MCMⅩⅭ", true),
+                Err("invalid roman number")
+            );
+        }
+    }
+
+    mod lowercase {
+        use super::*;
+
+        #[test]
+        fn test_front_matter() {
+            let expected = ["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix", "x"];
+            for (number, numeral) in (1..=10).zip(expected) {
+                assert_eq!(
+                    roman_case_converter(number, RomanStyle::Classic, RomanCase::Lower),
+                    Ok(String::from(numeral))
+                );
+                assert_eq!(arabic_style_converter(numeral, true), Ok(number));
+            }
+        }
+
+        #[test]
+        fn test_unicode() {
+            assert_eq!(
+                roman_case_converter(12, RomanStyle::Unicode, RomanCase::Lower),
+                Ok(String::from("ⅻ"))
+            );
+            assert_eq!(
+                roman_case_converter(1_990, RomanStyle::Unicode, RomanCase::Lower),
+                Ok(String::from("ⅿⅽⅿⅹⅽ"))
+            );
+            assert_eq!(arabic_style_converter("ⅿⅽⅿⅹⅽ", true), Ok(1_990));
+        }
+
+        #[test]
+        fn test_every_style() {
+            let styles = [
+                (RomanStyle::Classic, 3_999, "mmmcmxcix"),
+                (RomanStyle::Vinculum, 5_001, "v\u{305}i"),
+                (RomanStyle::VinculumUnderscore, 15_010, "_x_vx"),
+                (RomanStyle::VinculumParentheses, 1_004_000, "(miv)"),
+                (RomanStyle::Apostrophus, 11_000, "cciↄↄciↄ"),
+                (RomanStyle::ApostrophusUnicode, 16_001, "ↂↁↀi"),
+                (RomanStyle::Concise(4), 499, "id"),
+            ];
+
+            for (style, number, expected) in styles {
+                let roman_number = roman_case_converter(number, style, RomanCase::Lower);
+                assert_eq!(roman_number, Ok(String::from(expected)));
+                assert_eq!(
+                    roman_case_converter(number, style, RomanCase::Upper),
+                    roman_style_converter(number, style)
+                );
+                if style != RomanStyle::Concise(4) {
+                    assert_eq!(arabic_style_converter(expected, true), Ok(number));
+                }
+            }
+        }
+
+        #[test]
+        fn test_case_found() {
+            assert_eq!(arabic_case_converter("xiv"), Ok((14, RomanCase::Lower)));
+            assert_eq!(arabic_case_converter("XIV"), Ok((14, RomanCase::Upper)));
+            assert_eq!(arabic_case_converter("Ⅻ"), Ok((12, RomanCase::Upper)));
+            assert_eq!(arabic_case_converter("ⅲ"), Ok((3, RomanCase::Lower)));
+        }
+
+        #[test]
+        fn test_mixed_case() {
+            assert_eq!(arabic_case_converter("Xiv"), Err("invalid roman number"));
+            assert_eq!(
+                arabic_style_converter("xIV", false),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_no_uppercase_form() {
+            for text in ["ª", "º", "xª", "ß"] {
+                assert_eq!(
+                    arabic_style_converter(text, true),
+                    Err("invalid roman number"),
+                    "{}",
+                    text
+                );
+                assert_eq!(
+                    arabic_style_converter(text, false),
+                    Err("invalid roman number"),
+                    "{}",
+                    text
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(arabic_case_converter("iiii"), Err("invalid roman number"));
+            assert_eq!(arabic_case_converter(""), Err("lower than 1"));
+        }
+    }
+
+    mod concise {
+        use super::*;
+        use crate::{arabic_lenient_converter, RomanParsing};
+
+        #[test]
+        fn test_499() {
+            let expected = ["CDXCIX", "LDVLIV", "XDIX", "VDIV", "ID"];
+            for (form, roman_number) in (0..=4).zip(expected) {
+                assert_eq!(
+                    roman_style_converter(499, RomanStyle::Concise(form)),
+                    Ok(String::from(roman_number))
+                );
+            }
+        }
+
+        #[test]
+        fn test_1_999() {
+            let expected = ["MCMXCIX", "MLMVLIV", "MXMIX", "MVMIV", "MIM"];
+            for (form, roman_number) in (0..=4).zip(expected) {
+                assert_eq!(
+                    roman_style_converter(1_999, RomanStyle::Concise(form)),
+                    Ok(String::from(roman_number))
+                );
+            }
+        }
+
+        #[test]
+        fn test_45() {
+            assert_eq!(
+                roman_style_converter(45, RomanStyle::Concise(1)),
+                Ok(String::from("VL"))
+            );
+        }
+
+        #[test]
+        fn test_form_0_is_classic() {
+            for number in 1..=3_999 {
+                assert_eq!(
+                    roman_style_converter(number, RomanStyle::Concise(0)),
+                    roman_style_converter(number, RomanStyle::Classic)
+                );
+            }
+        }
+
+        #[test]
+        fn test_lenient_parsing() {
+            for form in 1..=4 {
+                for number in 1..=3_999 {
+                    let roman_number =
+                        roman_style_converter(number, RomanStyle::Concise(form)).unwrap();
+                    let result =
+                        arabic_lenient_converter(&roman_number, RomanParsing::LenientSubtractive);
+                    assert_eq!(result.map(|reading| reading.value() as u32), Ok(number));
+                }
+            }
+        }
+
+        #[test]
+        fn test_invalid_form() {
+            assert_eq!(
+                roman_style_converter(499, RomanStyle::Concise(5)),
+                Err("invalid form")
+            );
+        }
+    }
+
+    mod exceptions {
+        use super::*;
+
+        #[test]
+        fn test_greater_than_3_999() {
+            assert_eq!(
+                roman_style_converter(4_000, RomanStyle::Classic),
+                Err("greater than 3999")
+            );
+        }
+
+        #[test]
+        fn test_greater_than_3_999_999() {
+            assert_eq!(
+                roman_style_converter(4_000_000, RomanStyle::VinculumParentheses),
+                Err("greater than 3999999")
+            );
+        }
+
+        #[test]
+        fn test_greater_than_399_999() {
+            assert_eq!(
+                roman_style_converter(400_000, RomanStyle::Apostrophus),
+                Err("greater than 399999")
+            );
+        }
+
+        #[test]
+        fn test_invalid_apostrophus() {
+            assert_eq!(
+                arabic_style_converter("ↀↀↀↀ", false),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("CↃ", false),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("CIↃↃ", false),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_lower_than_1() {
+            assert_eq!(
+                roman_style_converter(0, RomanStyle::Vinculum),
+                Err("lower than 1")
+            );
+        }
+
+        #[test]
+        fn test_non_canonical_vinculum() {
+            assert_eq!(
+                arabic_style_converter("(III)", true),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("(IV)M", true),
+                Err("invalid roman number")
+            );
+        }
+
+        #[test]
+        fn test_mixed_notations() {
+            assert_eq!(
+                arabic_style_converter("(X)_V", true),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("X_V", true),
+                Err("invalid roman number")
+            );
+            assert_eq!(
+                arabic_style_converter("(XV", true),
+                Err("invalid roman number")
+            );
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt;
+
+use crate::arabic_converter::unicode_normalizer;
+use crate::{arabic_converter, arabic_lenient_converter, RomanParsing};
+
+const NON_REPEATABLE: [char; 3] = ['V', 'L', 'D'];
+const SUBTRACTIVE_PAIRS: [(char, char); 6] = [
+    ('I', 'V'),
+    ('I', 'X'),
+    ('X', 'L'),
+    ('X', 'C'),
+    ('C', 'D'),
+    ('C', 'M'),
+];
+
+/// A rule of the standard Roman notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RomanRule {
+    /// The number is empty.
+    Empty,
+    /// A character is not a Roman numeral (XZ).
+    InvalidCharacter,
+    /// V, L and D cannot repeat (VV).
+    NonRepeatable,
+    /// I, X, C and M cannot repeat more than three times (IIII).
+    TooManyRepetitions,
+    /// Only I, X and C can be subtracted, and only from the next two larger symbols (IC, VX).
+    InvalidSubtraction,
+    /// A subtracted symbol cannot be repeated (IIX).
+    RepeatedSubtraction,
+    /// A subtractive pair cannot be repeated (XIXIX).
+    RepeatedSubtractivePair,
+    /// A symbol is larger than the symbols before it allow (VIX, IXX).
+    WrongOrder,
+}
+
+/// A violation of a rule of the standard Roman notation, found by `roman_validator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RomanViolation {
+    position: usize,
+    rule: RomanRule,
+    pattern: String,
+}
+
+impl RomanViolation {
+    /// Returns the position of the character violating the rule.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the violated rule.
+    pub fn rule(&self) -> RomanRule {
+        self.rule
+    }
+
+    /// Returns the characters violating the rule.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl fmt::Display for RomanViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut letters = self.pattern.chars();
+        let first = letters.next().unwrap_or_default();
+        let second = letters.next().unwrap_or_default();
+
+        match self.rule {
+            RomanRule::Empty => write!(f, "empty roman number"),
+            RomanRule::InvalidCharacter => write!(f, "{} is not a roman numeral", first),
+            RomanRule::NonRepeatable => write!(f, "{} cannot repeat", first),
+            RomanRule::TooManyRepetitions => {
+                write!(f, "{} cannot repeat more than three times", first)
+            }
+            RomanRule::InvalidSubtraction => {
+                write!(f, "{} cannot be subtracted from {}", first, second)
+            }
+            RomanRule::RepeatedSubtraction => {
+                write!(f, "{} cannot be subtracted after another {}", first, first)
+            }
+            RomanRule::RepeatedSubtractivePair => {
+                write!(f, "repeated subtractive pair {}", self.pattern)
+            }
+            RomanRule::WrongOrder => write!(f, "{} is out of order", first),
+        }?;
+
+        write!(f, " at position {}", self.position)
+    }
+}
+
+fn get_arabic_number(letter: char) -> Option<u16> {
+    match letter {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+fn violation(position: usize, rule: RomanRule, pattern: &[char]) -> RomanViolation {
+    RomanViolation {
+        position,
+        rule,
+        pattern: pattern.iter().collect(),
+    }
+}
+
+/// Checks a Roman number against the rules of the standard notation
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to check.
+///
+/// The result lists every violation with the position of the character (not byte) where it was found,
+/// and is empty if and only if `arabic_converter` accepts the number written in uppercase ASCII letters.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::{roman_validator, RomanRule};
+///
+/// let result = roman_validator("XIV");
+/// assert!(result.is_empty());
+///
+/// let result = roman_validator("XIXIX");
+/// assert_eq!(RomanRule::RepeatedSubtractivePair, result[0].rule());
+/// assert_eq!(3, result[0].position());
+/// assert_eq!("repeated subtractive pair IX at position 3", result[0].to_string());
+///
+/// let result = roman_validator("VV");
+/// assert_eq!("V cannot repeat at position 1", result[0].to_string());
+/// ```
+pub fn roman_validator(roman_number: &str) -> Vec<RomanViolation> {
+    if roman_number.is_empty() {
+        return vec![violation(0, RomanRule::Empty, &[])];
+    }
+
+    let mut violations = Vec::new();
+    let mut symbols = Vec::new();
+    for (position, letter) in roman_number.chars().enumerate() {
+        match get_arabic_number(letter) {
+            Some(value) => symbols.push((position, letter, value)),
+            None => violations.push(violation(position, RomanRule::InvalidCharacter, &[letter])),
+        }
+    }
+
+    let mut run = 0;
+    for k in 0..symbols.len() {
+        let (position, letter, value) = symbols[k];
+        let previous = k.checked_sub(1).map(|i| symbols[i]);
+        let next = symbols.get(k + 1).copied();
+
+        run = match previous {
+            Some((_, previous_letter, _)) if previous_letter == letter => run + 1,
+            _ => 1,
+        };
+        if run > 1 && NON_REPEATABLE.contains(&letter) {
+            violations.push(violation(position, RomanRule::NonRepeatable, &[letter]));
+        } else if run == 4 {
+            violations.push(violation(
+                position,
+                RomanRule::TooManyRepetitions,
+                &[letter],
+            ));
+        }
+
+        let mut is_repeated_pair = false;
+        if let Some((_, next_letter, _)) = next.filter(|next| next.2 > value) {
+            if !SUBTRACTIVE_PAIRS.contains(&(letter, next_letter)) {
+                violations.push(violation(
+                    position,
+                    RomanRule::InvalidSubtraction,
+                    &[letter, next_letter],
+                ));
+            } else if run > 1 {
+                violations.push(violation(
+                    position,
+                    RomanRule::RepeatedSubtraction,
+                    &[letter],
+                ));
+            } else if k >= 2 && (symbols[k - 2].1, symbols[k - 1].1) == (letter, next_letter) {
+                is_repeated_pair = true;
+                violations.push(violation(
+                    position,
+                    RomanRule::RepeatedSubtractivePair,
+                    &[letter, next_letter],
+                ));
+            }
+        }
+
+        if k >= 2 && !is_repeated_pair {
+            let subtracted = symbols[k - 2].2;
+            if subtracted < symbols[k - 1].2 && value >= subtracted {
+                violations.push(violation(position, RomanRule::WrongOrder, &[letter]));
+            }
+        }
+    }
+
+    if violations.is_empty() && arabic_converter(roman_number).is_err() {
+        let canonical = roman_canonicalizer(roman_number).unwrap_or_default();
+        let position = roman_number
+            .chars()
+            .zip(canonical.chars())
+            .take_while(|(letter, canonical_letter)| letter == canonical_letter)
+            .count()
+            .min(symbols.len() - 1);
+
+        violations.push(violation(
+            position,
+            RomanRule::WrongOrder,
+            &[symbols[position].1],
+        ));
+    }
+
+    violations
+}
+
+/// Converts a Roman number readable with the lenient rules to its standard form
+///
+/// # Arguments
+///
+/// * `roman_number` - The Roman number to convert, in uppercase or lowercase letters.
+///
+/// The number is read with `RomanParsing::LenientSubtractive`, so additive forms (IIII, VIIII) and
+/// non-standard subtractions (IIX, IC) are accepted. Unicode Roman numeral characters are accepted too.
+///
+/// # Examples
+///
+/// ```
+/// use italian_numbers::roman_canonicalizer;
+///
+/// let result = roman_canonicalizer("IIII");
+/// assert_eq!(Ok(String::from("IV")), result);
+///
+/// let result = roman_canonicalizer("MDCCCCLXXXXVIIII");
+/// assert_eq!(Ok(String::from("MCMXCIX")), result);
+///
+/// let result = roman_canonicalizer("ic");
+/// assert_eq!(Ok(String::from("XCIX")), result);
+/// ```
+pub fn roman_canonicalizer(roman_number: &str) -> Result<String, &'static str> {
+    let roman_number = unicode_normalizer(&roman_number.to_uppercase());
+    let reading = arabic_lenient_converter(&roman_number, RomanParsing::LenientSubtractive)?;
+
+    Ok(reading.canonical().to_string())
+}
+
+/* TESTS */
+
+#[cfg(test)]
+mod tests {
+    use super::{roman_canonicalizer, roman_validator, RomanRule};
+    use crate::arabic_converter;
+
+    fn rules(roman_number: &str) -> Vec<(usize, RomanRule)> {
+        roman_validator(roman_number)
+            .iter()
+            .map(|violation| (violation.position(), violation.rule()))
+            .collect()
+    }
+
+    mod validator {
+        use super::*;
+
+        #[test]
+        fn test_canonical() {
+            for number in 1..=3_999 {
+                let roman_number = crate::roman_converter(number).unwrap();
+                assert!(roman_validator(&roman_number).is_empty());
+            }
+        }
+
+        #[test]
+        fn test_agrees_with_arabic_converter() {
+            let letters = ['I', 'V', 'X', 'L', 'C', 'D', 'M'];
+            let mut roman_numbers = vec![String::new()];
+            for _ in 0..4 {
+                roman_numbers = roman_numbers
+                    .iter()
+                    .flat_map(|prefix| {
+                        letters
+                            .iter()
+                            .map(move |letter| format!("{}{}", prefix, letter))
+                    })
+                    .collect();
+
+                for roman_number in &roman_numbers {
+                    assert_eq!(
+                        roman_validator(roman_number).is_empty(),
+                        arabic_converter(roman_number.clone()).is_ok(),
+                        "{}",
+                        roman_number
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_invalid_character() {
+            assert_eq!(rules("XZI"), vec![(1, RomanRule::InvalidCharacter)]);
+        }
+
+        #[test]
+        fn test_non_repeatable() {
+            assert_eq!(rules("VV"), vec![(1, RomanRule::NonRepeatable)]);
+            assert_eq!(rules("MDDC"), vec![(2, RomanRule::NonRepeatable)]);
+        }
+
+        #[test]
+        fn test_too_many_repetitions() {
+            assert_eq!(rules("XIIII"), vec![(4, RomanRule::TooManyRepetitions)]);
+        }
+
+        #[test]
+        fn test_invalid_subtraction() {
+            assert_eq!(rules("IC"), vec![(0, RomanRule::InvalidSubtraction)]);
+            assert_eq!(rules("MVX"), vec![(1, RomanRule::InvalidSubtraction)]);
+        }
+
+        #[test]
+        fn test_repeated_subtraction() {
+            assert_eq!(rules("XIIX"), vec![(2, RomanRule::RepeatedSubtraction)]);
+        }
+
+        #[test]
+        fn test_repeated_subtractive_pair() {
+            assert_eq!(
+                rules("XIXIX"),
+                vec![(3, RomanRule::RepeatedSubtractivePair)]
+            );
+        }
+
+        #[test]
+        fn test_wrong_order() {
+            assert_eq!(rules("IXX"), vec![(2, RomanRule::WrongOrder)]);
+            assert_eq!(rules("VIX"), vec![(0, RomanRule::WrongOrder)]);
+        }
+
+        #[test]
+        fn test_empty() {
+            assert_eq!(rules(""), vec![(0, RomanRule::Empty)]);
+        }
+
+        #[test]
+        fn test_messages() {
+            let messages = roman_validator("ICZ")
+                .iter()
+                .map(|violation| violation.to_string())
+                .collect::<Vec<String>>();
+            assert_eq!(
+                messages,
+                vec![
+                    "Z is not a roman numeral at position 2",
+                    "I cannot be subtracted from C at position 0"
+                ]
+            );
+        }
+    }
+
+    mod canonicalizer {
+        use super::*;
+
+        #[test]
+        fn test_additive() {
+            assert_eq!(roman_canonicalizer("VIIII"), Ok(String::from("IX")));
+            assert_eq!(roman_canonicalizer("XXXXX"), Ok(String::from("L")));
+        }
+
+        #[test]
+        fn test_subtractive() {
+            assert_eq!(roman_canonicalizer("XIIX"), Ok(String::from("XVIII")));
+            assert_eq!(roman_canonicalizer("MIM"), Ok(String::from("MCMXCIX")));
+        }
+
+        #[test]
+        fn test_canonical() {
+            assert_eq!(roman_canonicalizer("MCMXC"), Ok(String::from("MCMXC")));
+        }
+
+        #[test]
+        fn test_unicode() {
+            assert_eq!(roman_canonicalizer("ⅩⅡⅡ"), Ok(String::from("XIV")));
+        }
+
+        #[test]
+        fn test_invalid() {
+            assert_eq!(roman_canonicalizer("IXX"), Err("invalid roman number"));
+            assert_eq!(roman_canonicalizer("ABC"), Err("invalid roman number"));
+        }
+    }
+}